@@ -3,6 +3,9 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier, Size, Variant};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BtnColor {
     #[default]
@@ -16,20 +19,16 @@ pub enum BtnColor {
     Error,
 }
 
-impl Display for BtnColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BtnColor::Neutral => write!(f, "btn-neutral"),
-            BtnColor::Primary => write!(f, "btn-primary"),
-            BtnColor::Secondary => write!(f, "btn-secondary"),
-            BtnColor::Accent => write!(f, "btn-accent"),
-            BtnColor::Info => write!(f, "btn-info"),
-            BtnColor::Success => write!(f, "btn-success"),
-            BtnColor::Warning => write!(f, "btn-warning"),
-            BtnColor::Error => write!(f, "btn-error"),
-        }
-    }
-}
+legacy_enum!(BtnColor => Color, "btn" {
+    Neutral => Neutral,
+    Primary => Primary,
+    Secondary => Secondary,
+    Accent => Accent,
+    Info => Info,
+    Success => Success,
+    Warning => Warning,
+    Error => Error,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BtnType {
@@ -61,17 +60,13 @@ pub enum BtnSize {
     Medium,
 }
 
-impl Display for BtnSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BtnSize::Default => write!(f, "btn-sm"),
-            BtnSize::ExtraSmall => write!(f, "btn-xs"),
-            BtnSize::Small => write!(f, "btn-sm"),
-            BtnSize::Medium => write!(f, "btn-md"),
-            BtnSize::Large => write!(f, "btn-lg"),
-        }
-    }
-}
+legacy_enum!(BtnSize => Size, "btn" {
+    Default => Sm,
+    ExtraSmall => Xs,
+    Small => Sm,
+    Medium => Md,
+    Large => Lg,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BtnShape {
@@ -102,18 +97,29 @@ pub enum BtnStyle {
     Link,
 }
 
-impl Display for BtnStyle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BtnStyle::None => write!(f, ""),
-            BtnStyle::Outline => write!(f, "btn-outline"),
-            BtnStyle::Dash => write!(f, "btn-dash"),
-            BtnStyle::Soft => write!(f, "btn-soft"),
-            BtnStyle::Ghost => write!(f, "btn-ghost"),
-            BtnStyle::Link => write!(f, "btn-link"),
-        }
-    }
-}
+legacy_enum!(BtnStyle => Variant, "btn" {
+    None => Default,
+    Outline => Outline,
+    Dash => Dash,
+    Soft => Soft,
+    Ghost => Ghost,
+    Link => Link,
+});
+
+/// `button` attributes that mean nothing on the `a` rendered for `BtnType::Link`.
+const BUTTON_ONLY_ATTRIBUTES: [&str; 11] = [
+    "disabled",
+    "form",
+    "formaction",
+    "formenctype",
+    "formmethod",
+    "formnovalidate",
+    "formtarget",
+    "name",
+    "popovertarget",
+    "popovertargetaction",
+    "value",
+];
 
 #[derive(Props, Clone, PartialEq)]
 pub struct BtnProps {
@@ -126,32 +132,48 @@ pub struct BtnProps {
     prefix_image_src: Option<String>,
     suffix_image_src: Option<String>,
     btn_type: Option<BtnType>,
-    btn_size: Option<BtnSize>,
-    btn_color: Option<BtnColor>,
+    btn_size: Option<Size>,
+    btn_color: Option<Color>,
     popover_target: Option<String>,
     popover_target_action: Option<String>,
     disabled_text: Option<String>,
     btn_shape: Option<BtnShape>,
-    btn_style: Option<BtnStyle>,
+    btn_style: Option<Variant>,
+    onclick: Option<EventHandler<MouseEvent>>,
+    /// For `BtnType::Link` the button only ones, `formaction`, `name` and so
+    /// on, are dropped.
+    #[props(extends = GlobalAttributes, extends = button)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Button(props: BtnProps) -> Element {
     let btn_type = props.btn_type.unwrap_or_default();
-    let btn_size = props.btn_size.unwrap_or_default();
-    let btn_color = props.btn_color.unwrap_or_default();
+    let btn_size = props.btn_size.unwrap_or(Size::Sm).class("btn");
+    let btn_color = props.btn_color.unwrap_or(Color::Neutral).class("btn");
     let btn_shape = props.btn_shape.unwrap_or_default();
-    let btn_style = props.btn_style.unwrap_or_default();
-    let class = props.class.unwrap_or_default();
+    let btn_style = props.btn_style.unwrap_or_default().class("btn");
+    let class = classes!(
+        "btn",
+        btn_color,
+        btn_size,
+        btn_shape,
+        btn_style,
+        props.class.unwrap_or_default()
+    );
     let disabled = props.disabled.filter(|&x| x);
+    let mut attributes = props.attributes;
+    attributes.extend(props.onclick.map(onclick));
 
     if props.btn_type == Some(BtnType::Link) {
+        attributes.retain(|attribute| !BUTTON_ONLY_ATTRIBUTES.contains(&attribute.name));
         rsx!(
             a {
-                class: "btn {class} {btn_color} {btn_size} {btn_shape} {btn_style}",
+                class,
                 id: props.id,
                 href: props.href,
                 target: props.target,
+                ..attributes,
                 if let Some(img_src) = props.prefix_image_src {
                         img {
                             src: "{img_src}",
@@ -170,13 +192,14 @@ pub fn Button(props: BtnProps) -> Element {
     } else {
         rsx!(
             button {
-                class: "btn {class} {btn_color} {btn_size} {btn_shape} {btn_style}",
+                class,
                 id: props.id,
                 disabled,
                 // We wanted to use popover but doesnt seem to work with daisy modals
                 "data-target": props.popover_target,
                 "type": "{btn_type}",
                 "data-disabled-text": props.disabled_text,
+                ..attributes,
                 if let Some(img_src) = props.prefix_image_src {
                     img { src: "{img_src}", width: "16" }
                 }
@@ -196,8 +219,8 @@ fn test_button() {
         class: Some("test".to_string()),
         href: None,
         target: None,
-        btn_color: Some(BtnColor::Primary),
-        btn_size: Some(BtnSize::Large),
+        btn_color: Some(Color::Primary),
+        btn_size: Some(Size::Lg),
         btn_type: Some(BtnType::Button),
         btn_shape: None,
         btn_style: None,
@@ -208,10 +231,12 @@ fn test_button() {
         disabled_text: None,
         popover_target: None,
         popover_target_action: None,
+        onclick: None,
+        attributes: vec![],
     };
 
     let expected =
-        r#"<button class="btn test btn-primary btn-lg  " id="id" type="button">Hello</button>"#;
+        r#"<button class="btn btn-primary btn-lg test" id="id" type="button">Hello</button>"#;
     let result = dioxus_ssr::render_element(Button(props));
     // println!("{}", result);
     assert_eq!(expected, result);
@@ -225,8 +250,8 @@ fn test_button_with_images() {
         class: Some("test".to_string()),
         href: None,
         target: None,
-        btn_color: Some(BtnColor::Primary),
-        btn_size: Some(BtnSize::Large),
+        btn_color: Some(Color::Primary),
+        btn_size: Some(Size::Lg),
         btn_type: Some(BtnType::Button),
         btn_shape: None,
         btn_style: None,
@@ -237,26 +262,97 @@ fn test_button_with_images() {
         disabled_text: None,
         popover_target: None,
         popover_target_action: None,
+        onclick: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<button class="btn test btn-primary btn-lg  " id="id" type="button"><img src="prefix.png" width="16"/>Hello<img src="suffix.png" width="16"/></button>"#;
+    let expected = r#"<button class="btn btn-primary btn-lg test" id="id" type="button"><img src="prefix.png" width="16"/>Hello<img src="suffix.png" width="16"/></button>"#;
     let result = dioxus_ssr::render_element(Button(props));
     // println!("{}", result);
     assert_eq!(expected, result);
 }
 
+// test extra attributes are passed through to the button
+#[test]
+fn test_button_attributes() {
+    let props = BtnProps {
+        children: rsx!("Save"),
+        class: None,
+        href: None,
+        target: None,
+        btn_color: None,
+        btn_size: None,
+        btn_type: Some(BtnType::Submit),
+        btn_shape: None,
+        btn_style: None,
+        id: None,
+        disabled: None,
+        prefix_image_src: None,
+        suffix_image_src: None,
+        disabled_text: None,
+        popover_target: None,
+        popover_target_action: None,
+        onclick: None,
+        attributes: vec![
+            Attribute::new("name", "intent", None, false),
+            Attribute::new("value", "publish", None, false),
+            Attribute::new("form", "post-form", None, false),
+            Attribute::new("aria-label", "Publish post", None, false),
+            Attribute::new("data-action", "click->editor#save", None, false),
+        ],
+    };
+
+    let expected = r#"<button class="btn btn-neutral btn-sm" type="submit" name="intent" value="publish" form="post-form" aria-label="Publish post" data-action="click->editor#save">Save</button>"#;
+    let result = dioxus_ssr::render_element(Button(props));
+    assert_eq!(expected, result);
+}
+
+// test button only attributes aren't put on a link
+#[test]
+fn test_button_link_attributes() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Button {
+            btn_type: BtnType::Link,
+            href: "/posts",
+            formaction: "/publish",
+            name: "intent",
+            aria_label: "Posts",
+            "Posts"
+        }
+    ));
+    assert_eq!(
+        result,
+        r#"<a class="btn btn-neutral btn-sm" href="/posts" aria-label="Posts">Posts</a>"#
+    );
+}
+
+// test an onclick handler doesn't change the markup
+#[test]
+fn test_button_onclick() {
+    fn app() -> Element {
+        rsx!(
+            Button { onclick: move |_| {}, "Save" }
+        )
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<button class="btn btn-neutral btn-sm" type="button">Save</button>"#;
+    assert_eq!(expected, dioxus_ssr::render(&dom));
+}
+
 // test all button schemes
 #[test]
 fn test_all_button_schemes() {
     let schemes = [
-        (BtnColor::Neutral, "btn-neutral"),
-        (BtnColor::Primary, "btn-primary"),
-        (BtnColor::Secondary, "btn-secondary"),
-        (BtnColor::Accent, "btn-accent"),
-        (BtnColor::Info, "btn-info"),
-        (BtnColor::Success, "btn-success"),
-        (BtnColor::Warning, "btn-warning"),
-        (BtnColor::Error, "btn-error"),
+        (Color::Neutral, "btn-neutral"),
+        (Color::Primary, "btn-primary"),
+        (Color::Secondary, "btn-secondary"),
+        (Color::Accent, "btn-accent"),
+        (Color::Info, "btn-info"),
+        (Color::Success, "btn-success"),
+        (Color::Warning, "btn-warning"),
+        (Color::Error, "btn-error"),
     ];
 
     for (scheme, expected_class) in schemes {
@@ -277,6 +373,8 @@ fn test_all_button_schemes() {
             disabled_text: None,
             popover_target: None,
             popover_target_action: None,
+            onclick: None,
+            attributes: vec![],
         };
 
         let result = dioxus_ssr::render_element(Button(props));
@@ -310,6 +408,8 @@ fn test_default_button_scheme() {
         disabled_text: None,
         popover_target: None,
         popover_target_action: None,
+        onclick: None,
+        attributes: vec![],
     };
 
     let result = dioxus_ssr::render_element(Button(props));
@@ -319,3 +419,28 @@ fn test_default_button_scheme() {
         result
     );
 }
+
+// the older enums still work as props
+#[test]
+fn test_button_legacy_enums() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Button { btn_color: BtnColor::Error, btn_size: BtnSize::Large, btn_style: BtnStyle::Outline, "Delete" }
+    ));
+    assert!(
+        result.contains("btn btn-error btn-lg btn-outline"),
+        "{}",
+        result
+    );
+}
+
+// a caller class overrides the matching default modifier
+#[test]
+fn test_button_class_overrides_modifiers() {
+    let result = dioxus_ssr::render_element(rsx!(
+        Button { class: "btn-secondary btn-xs w-full", "Save" }
+    ));
+    assert_eq!(
+        result,
+        r#"<button class="btn btn-secondary btn-xs w-full" type="button">Save</button>"#
+    );
+}
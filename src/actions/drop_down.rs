@@ -3,6 +3,8 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Direction {
     #[default]
@@ -33,7 +35,7 @@ impl Display for Direction {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct DropDownProps {
-    child: Element,
+    children: Element,
     haver: Option<bool>,
     carat: Option<bool>,
     btn_text: String,
@@ -41,6 +43,8 @@ pub struct DropDownProps {
     direction: Option<Direction>,
     prefix_image_src: Option<String>,
     suffix_image_src: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
@@ -52,83 +56,96 @@ pub fn DropDown(props: DropDownProps) -> Element {
     } else {
         ""
     };
-    let class = format!(" {} {} ", class, haver);
+    let carat = props.carat.unwrap_or(false);
+
     rsx!(
-        div { class: "dropdown {class} {direction}",
-            div {tabindex: "0", role:"button", class:"btn m-1", "Click" }
+        div { class: classes!("dropdown", direction, haver, class), ..props.attributes,
+            div {
+                tabindex: "0",
+                role: "button",
+                class: "btn btn-sm m-1 flex flex-nowrap justify-between",
+                "aria-haspopup": "true",
+                if let Some(img_src) = props.prefix_image_src {
+                    img { src: "{img_src}", class: "mr-2", width: "16" }
+                }
+                span { class: "truncate", "{props.btn_text}" }
+                if let Some(img_src) = props.suffix_image_src {
+                    img { src: "{img_src}", class: "ml-2", width: "12" }
+                } else if carat {
+                    svg {
+                        xmlns: "http://www.w3.org/2000/svg",
+                        width: "12",
+                        height: "12",
+                        view_box: "0 0 24 24",
+                        fill: "none",
+                        stroke: "currentColor",
+                        stroke_width: "2",
+                        stroke_linecap: "round",
+                        stroke_linejoin: "round",
+                        class: "ml-2",
+                        path { d: "m6 9 6 6 6-6" }
+                    }
+                }
+            }
             ul {
                 tabindex: "0",
                 class: "dropdown-content menu bg-base-100 rounded-box z-1 w-52 p-2 shadow-sm",
-                li { a { "Item 1" } }
-                li { a { "Item 2" } }
-                li { a { "Item 3" } }
-                // {props.children}
+                {props.children}
             }
         }
     )
 }
 
-#[component]
-pub fn DropDown1(props: DropDownProps) -> Element {
-    let direction = props.direction.unwrap_or_default();
-
-    rsx!(
-        // div { class: "dropdown {props.class.clone().unwrap_or_default()} {direction}",
-        //     label {
-        //         tabindex: "0",
-        //         class: "btn btn-default btn-sm m-1 w-full flex flex-nowrap justify-between",
-        //         "aria-haspopup": "true",
-        //         if let Some(img_src) = props.prefix_image_src {
-        //             img { src: "{img_src}", class: "mr-2", width: "16" }
-        //         }
-        //         span { class: "truncate", "{props.button_text}" }
-        //         if let Some(img_src) = props.suffix_image_src {
-        //             img { src: "{img_src}", class: "ml-2", width: "12" }
-        //         } else if props.carat.is_some() && props.carat.unwrap() {
-        //             div { class: "dropdown-caret" }
-        //         }
-        //     }
-        //     ul {
-        //         tabindex: "0",
-        //         class: "dropdown-content z-[1] menu p-2 shadow bg-base-100 rounded-box w-52 {direction}",
-        //         {props.child}
-        //     }
-        // }
-    )
-}
-
 #[derive(Props, Clone, PartialEq)]
 pub struct DropDownLinkProps {
     href: String,
     target: Option<String>,
     popover_target: Option<String>,
     class: Option<String>,
+    icon: Option<String>,
+    disabled: Option<bool>,
+    danger: Option<bool>,
     children: Element,
+    onclick: Option<EventHandler<MouseEvent>>,
+    #[props(extends = GlobalAttributes, extends = a)]
+    attributes: Vec<Attribute>,
 }
 
+/// An entry in a `DropDown`. Use `danger` for destructive actions such as Delete.
 #[component]
 pub fn DropDownLink(props: DropDownLinkProps) -> Element {
-    let class = format!("dropdown-item {}", props.class.unwrap_or_default());
+    let danger = if props.danger.unwrap_or(false) {
+        "text-error"
+    } else {
+        ""
+    };
+    let class = classes!("dropdown-item", danger, props.class.unwrap_or_default());
 
-    if let Some(trigger) = &props.popover_target {
+    if props.disabled.unwrap_or(false) {
         rsx!(
-            li {
-                a {
-                    class: "{class}",
-                    "data-target": "{trigger}",
-                    target: props.target,
-                    href: "{props.href}",
+            li { class: "menu-disabled",
+                a { class, "aria-disabled": "true", ..props.attributes,
+                    if let Some(icon) = props.icon {
+                        img { width: "16", height: "16", src: "{icon}" }
+                    }
                     {props.children}
                 }
             }
         )
     } else {
+        let mut attributes = props.attributes;
+        attributes.extend(props.onclick.map(onclick));
         rsx!(
             li {
                 a {
-                    class: "{class}",
+                    class,
+                    "data-target": props.popover_target,
                     target: props.target,
                     href: "{props.href}",
+                    ..attributes,
+                    if let Some(icon) = props.icon {
+                        img { width: "16", height: "16", src: "{icon}" }
+                    }
                     {props.children}
                 }
             }
@@ -136,21 +153,107 @@ pub fn DropDownLink(props: DropDownLinkProps) -> Element {
     }
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct DropDownDividerProps {
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A horizontal rule between groups of `DropDownLink`s.
+#[component]
+pub fn DropDownDivider(props: DropDownDividerProps) -> Element {
+    rsx!(li {
+        role: "separator",
+        class: "my-1 border-t border-base-300",
+        ..props.attributes
+    })
+}
+
 #[test]
-fn test_modal() {
+fn test_drop_down() {
     let props = DropDownProps {
-        child: rsx!("Hello"),
+        children: rsx!(
+            DropDownLink { href: "/edit", "Edit" }
+        ),
         haver: Some(true),
-        carat: Some(true),
-        btn_text: "".to_string(),
-        class: Some(String::new()),
-        direction: Some(Direction::Start),
-        prefix_image_src: Some(String::new()),
-        suffix_image_src: Some(String::new()),
+        carat: None,
+        btn_text: "Actions".to_string(),
+        class: Some("test".to_string()),
+        direction: Some(Direction::End),
+        prefix_image_src: Some("prefix.png".to_string()),
+        suffix_image_src: Some("suffix.png".to_string()),
+        attributes: vec![],
     };
 
-    let expected = r#"<form action="test" method="post"><dialog class="modal test" id="id" popover="auto">Hello</dialog></form>"#;
+    let expected = r#"<div class="dropdown dropdown-end dropdown-hover test"><div tabindex="0" role="button" class="btn btn-sm m-1 flex flex-nowrap justify-between" aria-haspopup="true"><img src="prefix.png" class="mr-2" width="16"/><span class="truncate">Actions</span><img src="suffix.png" class="ml-2" width="12"/></div><ul tabindex="0" class="dropdown-content menu bg-base-100 rounded-box z-1 w-52 p-2 shadow-sm"><li><a class="dropdown-item" href="/edit">Edit</a></li></ul></div>"#;
     let result = dioxus_ssr::render_element(DropDown(props));
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_drop_down_carat() {
+    let props = DropDownProps {
+        children: rsx!(),
+        haver: None,
+        carat: Some(true),
+        btn_text: "...".to_string(),
+        class: None,
+        direction: None,
+        prefix_image_src: None,
+        suffix_image_src: None,
+        attributes: vec![],
+    };
+
+    let result = dioxus_ssr::render_element(DropDown(props));
+    assert!(result.starts_with(r#"<div class="dropdown dropdown-bottom">"#));
+    assert!(result.contains(r#"<span class="truncate">...</span><svg"#));
+    assert!(result.contains(r#"<path d="m6 9 6 6 6-6"></path>"#));
+}
+
+#[test]
+fn test_drop_down_link_variants() {
+    let props = DropDownLinkProps {
+        href: "#".to_string(),
+        target: Some("_top".to_string()),
+        popover_target: Some("delete-trigger".to_string()),
+        class: None,
+        icon: Some("trash.svg".to_string()),
+        disabled: None,
+        danger: Some(true),
+        children: rsx!("Delete"),
+        onclick: None,
+        attributes: vec![],
+    };
+
+    let expected = r##"<li><a class="dropdown-item text-error" data-target="delete-trigger" target="_top" href="#"><img width="16" height="16" src="trash.svg"/>Delete</a></li>"##;
+    let result = dioxus_ssr::render_element(DropDownLink(props));
+    assert_eq!(expected, result);
+
+    let props = DropDownLinkProps {
+        href: "/archive".to_string(),
+        target: None,
+        popover_target: None,
+        class: None,
+        icon: None,
+        disabled: Some(true),
+        danger: None,
+        children: rsx!("Archive"),
+        onclick: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<li class="menu-disabled"><a class="dropdown-item" aria-disabled="true">Archive</a></li>"#;
+    let result = dioxus_ssr::render_element(DropDownLink(props));
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_drop_down_divider() {
+    let result =
+        dioxus_ssr::render_element(DropDownDivider(DropDownDividerProps { attributes: vec![] }));
+    assert_eq!(
+        r#"<li role="separator" class="my-1 border-t border-base-300"></li>"#,
+        result
+    );
+}
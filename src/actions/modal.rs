@@ -1,8 +1,11 @@
 #![allow(non_snake_case)]
-
+// The `Props` derive reads the deprecated `ModalProps::btn_color`.
+#![allow(deprecated)]
 use dioxus::prelude::*;
 
-use crate::BtnColor;
+use crate::attributes::use_return_focus;
+use crate::classes;
+use crate::style::{Color, Modifier};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum DialogType {
@@ -23,13 +26,30 @@ pub struct ModalProps {
     #[props(default = String::new())]
     class: String,
 
-    #[props(default = BtnColor::Info)]
-    btn_color: BtnColor,
+    /// Unused since the trigger moved out into `ModalTrigger`. Kept so
+    /// existing callers still compile.
+    #[deprecated(note = "set `btn_color` on the `ModalTrigger` instead")]
+    #[props(default = Color::Info, into)]
+    btn_color: Color,
 
     #[props(default = DialogType::Checkbox)]
     modal_type: DialogType,
 
     children: Element,
+
+    /// Submitting the modal's form, which is rendered for this even without
+    /// a `submit_action`.
+    onsubmit: Option<EventHandler<FormEvent>>,
+
+    /// Opens and closes the modal from a client-side app, instead of the
+    /// `modal_type` mechanism. Esc and backdrop clicks set it to `false`.
+    open: Option<Signal<bool>>,
+
+    /// Called after the modal closes itself.
+    on_close: Option<EventHandler<()>>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 /// There are 3 methods to use modals
@@ -40,101 +60,212 @@ pub struct ModalProps {
 /// 3. Using <a> anchor links
 ///    A link adds a parameter to the URL and you only see the modal when the URL has that parameter
 ///
+/// Use a `ModalTrigger` with the same `trigger_id` and `modal_type` to open it.
 #[component]
 pub fn Modal(props: ModalProps) -> Element {
+    if props.open.is_some() {
+        return rsx!(SignalModal { ..props });
+    }
+
     match props.modal_type {
-        // <button class="btn" onclick="my_modal_2.showModal()">open modal</button>
-        DialogType::Default => Default(props),
-        // <a href="#my_modal_8" class="btn">open modal</a>
-        DialogType::AnchorLink => AnchorLink(props),
-        // <label for="my_modal_7" class="btn">open modal</label>
-        DialogType::Checkbox => Checkbox(props),
+        DialogType::Default => DialogModal(props),
+        DialogType::AnchorLink => AnchorLinkModal(props),
+        DialogType::Checkbox => CheckboxModal(props),
     }
 }
 
-fn Default(props: ModalProps) -> Element {
-    rsx!(
-        dialog { id:"my_modal_2", class:"modal",
-            div {class:"modal-box",
-                h3 { class:"text-lg font-bold", "Hello!"}
-                p { class:"py-4", "Press ESC key or click outside to close"}
-            }
-            form { method:"dialog", class:"modal-backdrop",
-                    button {"close"}
+/// The modal-box, wrapped in a POST form when we have a `submit_action` or
+/// an `onsubmit` handler.
+fn ModalBox(
+    submit_action: String,
+    onsubmit: Option<EventHandler<FormEvent>>,
+    children: Element,
+) -> Element {
+    if submit_action.is_empty() && onsubmit.is_none() {
+        rsx!(
+            div { class: "modal-box", {children} }
+        )
+    } else {
+        let action = (!submit_action.is_empty()).then_some(submit_action);
+        let listeners: Vec<Attribute> = onsubmit
+            .map(dioxus_elements::events::onsubmit)
+            .into_iter()
+            .collect();
+        rsx!(
+            form { action, method: "post", ..listeners,
+                div { class: "modal-box", {children} }
             }
-        },
+        )
+    }
+}
+
+/// Only rendered while `open` is set, and focused on mount so Esc works.
+#[component]
+fn SignalModal(props: ModalProps) -> Element {
+    let Some(mut open) = props.open else {
+        return rsx!();
+    };
+    let on_close = props.on_close;
+    let mut close = move || {
+        open.set(false);
+        if let Some(on_close) = on_close {
+            on_close.call(());
+        }
+    };
+
+    if !open() {
+        return rsx!();
+    }
+
+    let id = (!props.trigger_id.is_empty()).then_some(props.trigger_id);
+    rsx!(
+        div {
+            role: "dialog",
+            "aria-modal": "true",
+            tabindex: "-1",
+            class: classes!("modal modal-open", props.class),
+            id,
+            onmounted: move |event| async move {
+                _ = event.set_focus(true).await;
+            },
+            onkeydown: move |event| {
+                if event.key() == Key::Escape {
+                    close();
+                }
+            },
+            ..props.attributes,
+            {ModalBox(props.submit_action, props.onsubmit, props.children)}
+            div { class: "modal-backdrop", onclick: move |_| close() }
+        }
     )
 }
 
-fn Checkbox(props: ModalProps) -> Element {
+fn DialogModal(props: ModalProps) -> Element {
     rsx!(
-        label { r#for: "my_modal_7", class: "btn", "Open" }
-        input { r#type: "checkbox", class: "modal-toggle", id: "my_modal_7" }
-        div { role: "dialog", class: "modal",
-            div {
-                class: "modal-box",
-                h3 { class: "text-lg font-bold", "Title" }
-                p { class: "py-4", "content ....." }
+        dialog { id: "{props.trigger_id}", class: classes!("modal", props.class), ..props.attributes,
+            {ModalBox(props.submit_action, props.onsubmit, props.children)}
+            form { method: "dialog", class: "modal-backdrop",
+                button { "close" }
             }
-            label { r#for: "my_modal_7", class: "modal-backdrop", "Close" }
         }
     )
 }
 
-fn AnchorLink(props: ModalProps) -> Element {
+fn CheckboxModal(props: ModalProps) -> Element {
     rsx!(
-        a { href: "#my_modal_8", class: "btn", "Open" }
-        div { role: "dialog", class: "modal", id: "my_modal_8",
-            div { class: "modal-box",
-                h3 { class: "text-lg font-bold", "Title" }
-                p { class: "py-4", "This modal works with anchor links" }
-                div { class: "modal-action",
-                    a { href: "#", class: "btn", "Yal!" }
-                }
-            }
+        input { r#type: "checkbox", class: "modal-toggle", id: "{props.trigger_id}" }
+        div { role: "dialog", class: classes!("modal", props.class), ..props.attributes,
+            {ModalBox(props.submit_action, props.onsubmit, props.children)}
+            label { r#for: "{props.trigger_id}", class: "modal-backdrop", "Close" }
         }
     )
 }
 
+fn AnchorLinkModal(props: ModalProps) -> Element {
+    rsx!(
+        div { role: "dialog", class: classes!("modal", props.class), id: "{props.trigger_id}", ..props.attributes,
+            {ModalBox(props.submit_action, props.onsubmit, props.children)}
+            a { href: "#", class: "modal-backdrop", "Close" }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ModalTriggerProps {
+    trigger_id: String,
+
+    #[props(default = String::new())]
+    class: String,
+
+    #[props(default = Color::Info, into)]
+    btn_color: Color,
+
+    #[props(default = DialogType::Checkbox)]
+    modal_type: DialogType,
+
+    children: Element,
+
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// Set to `true` on click, for a `Modal` with the same `open` signal.
+    /// Focus comes back to the trigger when the modal closes.
+    open: Option<Signal<bool>>,
+
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Opens the `Modal` with the same `trigger_id`, using the mechanism that
+/// matches its `DialogType`.
+#[component]
+pub fn ModalTrigger(props: ModalTriggerProps) -> Element {
+    if props.open.is_some() {
+        return rsx!(SignalModalTrigger { ..props });
+    }
+
+    let class = classes!("btn", props.btn_color.class("btn"), props.class);
+    let mut attributes = props.attributes;
+    attributes.extend(props.onclick.map(onclick));
+
+    match props.modal_type {
+        DialogType::Default => rsx!(
+            button {
+                class,
+                r#type: "button",
+                "data-modal": "{props.trigger_id}",
+                "onclick": "document.getElementById(this.dataset.modal).showModal()",
+                ..attributes,
+                {props.children}
+            }
+        ),
+        DialogType::Checkbox => rsx!(
+            label { r#for: "{props.trigger_id}", class, ..attributes, {props.children} }
+        ),
+        DialogType::AnchorLink => rsx!(
+            a { href: "#{props.trigger_id}", class, ..attributes, {props.children} }
+        ),
+    }
+}
+
 #[component]
-pub fn Modal_1(props: ModalProps) -> Element {
-    rsx!()
-    // match props.submit_action {
-    //     Some(action) => {
-    //         rsx!(
-    //             form { action: "{action}", method: "post",
-    //                 dialog {
-    //                     class: "modal {props.class.clone().unwrap_or_default()}",
-    //                     id: "{props.trigger_id}",
-    //                     popover: "auto",
-    //                     {props.children}
-    //                 }
-    //             }
-    //         )
-    //     }
-    //     _ => {
-    //         rsx!(
-    //             dialog {
-    //                 class: "modal {props.class.clone().unwrap_or_default()}",
-    //                 id: "{props.trigger_id}",
-    //                 popover: "auto",
-    //                 {props.children}
-    //             }
-    //         )
-    //     }
-    // }
+fn SignalModalTrigger(props: ModalTriggerProps) -> Element {
+    let Some(mut open) = props.open else {
+        return rsx!();
+    };
+    let onmounted = use_return_focus(open);
+    let onclick = props.onclick;
+
+    rsx!(
+        button {
+            class: classes!("btn", props.btn_color.class("btn"), props.class),
+            r#type: "button",
+            "aria-haspopup": "dialog",
+            onclick: move |event| {
+                open.set(true);
+                if let Some(onclick) = onclick {
+                    onclick.call(event);
+                }
+            },
+            onmounted,
+            ..props.attributes,
+            {props.children}
+        }
+    )
 }
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ModalBodyProps {
     children: Element,
     class: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
+/// The content of a `Modal`, placed inside the `modal-box` it renders.
 #[component]
 pub fn ModalBody(props: ModalBodyProps) -> Element {
     rsx!(
-        div { class: "modal-box {props.class.clone().unwrap_or_default()}", {props.children} }
+        div { class: classes!("modal-body", props.class.clone().unwrap_or_default()), ..props.attributes, {props.children} }
     )
 }
 
@@ -142,41 +273,185 @@ pub fn ModalBody(props: ModalBodyProps) -> Element {
 pub struct ModalActionProps {
     children: Element,
     class: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn ModalAction(props: ModalActionProps) -> Element {
     rsx!(
-        div { class: "modal-action {props.class.clone().unwrap_or_default()}", {props.children} }
+        div { class: classes!("modal-action", props.class.clone().unwrap_or_default()), ..props.attributes, {props.children} }
     )
 }
 
 #[test]
-fn test_modal() {
+fn test_modal_default() {
+    let props = ModalProps {
+        children: rsx!("Hello"),
+        class: "test".to_string(),
+        submit_action: String::new(),
+        trigger_id: "id".to_string(),
+        btn_color: Color::Info,
+        modal_type: DialogType::Default,
+        onsubmit: None,
+        open: None,
+        on_close: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<dialog id="id" class="modal test"><div class="modal-box">Hello</div><form method="dialog" class="modal-backdrop"><button>close</button></form></dialog>"#;
+    let result = dioxus_ssr::render_element(Modal(props));
+    // println!("{}", result);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_modal_default_with_submit_action() {
     let props = ModalProps {
         children: rsx!("Hello"),
-        class: Some("test".to_string()),
-        submit_action: Some("test".to_string()),
+        class: "test".to_string(),
+        submit_action: "test".to_string(),
         trigger_id: "id".to_string(),
+        btn_color: Color::Info,
+        modal_type: DialogType::Default,
+        onsubmit: None,
+        open: None,
+        on_close: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<form action="test" method="post"><dialog class="modal test" id="id" popover="auto">Hello</dialog></form>"#;
+    let expected = r#"<dialog id="id" class="modal test"><form action="test" method="post"><div class="modal-box">Hello</div></form><form method="dialog" class="modal-backdrop"><button>close</button></form></dialog>"#;
     let result = dioxus_ssr::render_element(Modal(props));
     // println!("{}", result);
     assert_eq!(expected, result);
 }
 
 #[test]
-fn test_modal_without_submit_action() {
+fn test_modal_checkbox() {
     let props = ModalProps {
         children: rsx!("Hello"),
-        class: Some("test".to_string()),
-        submit_action: None,
+        class: "test".to_string(),
+        submit_action: "test".to_string(),
         trigger_id: "id".to_string(),
+        btn_color: Color::Info,
+        modal_type: DialogType::Checkbox,
+        onsubmit: None,
+        open: None,
+        on_close: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<dialog class="modal test" id="id" popover="auto">Hello</dialog>"#;
+    let expected = r#"<input type="checkbox" class="modal-toggle" id="id"/><div role="dialog" class="modal test"><form action="test" method="post"><div class="modal-box">Hello</div></form><label for="id" class="modal-backdrop">Close</label></div>"#;
     let result = dioxus_ssr::render_element(Modal(props));
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_modal_anchor_link() {
+    let props = ModalProps {
+        children: rsx!("Hello"),
+        class: String::new(),
+        submit_action: String::new(),
+        trigger_id: "id".to_string(),
+        btn_color: Color::Info,
+        modal_type: DialogType::AnchorLink,
+        onsubmit: None,
+        open: None,
+        on_close: None,
+        attributes: vec![],
+    };
+
+    let expected = r##"<div role="dialog" class="modal" id="id"><div class="modal-box">Hello</div><a href="#" class="modal-backdrop">Close</a></div>"##;
+    let result = dioxus_ssr::render_element(Modal(props));
+    // println!("{}", result);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_modal_with_body_and_action() {
+    let props = ModalProps {
+        children: rsx!(
+            ModalBody { "Are you sure?" }
+            ModalAction { "Yes" }
+        ),
+        class: String::new(),
+        submit_action: String::new(),
+        trigger_id: "id".to_string(),
+        btn_color: Color::Info,
+        modal_type: DialogType::Checkbox,
+        onsubmit: None,
+        open: None,
+        on_close: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<input type="checkbox" class="modal-toggle" id="id"/><div role="dialog" class="modal"><div class="modal-box"><div class="modal-body">Are you sure?</div><div class="modal-action">Yes</div></div><label for="id" class="modal-backdrop">Close</label></div>"#;
+    let result = dioxus_ssr::render_element(Modal(props));
+    // println!("{}", result);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_modal_triggers() {
+    let triggers = [
+        (
+            DialogType::Default,
+            r#"<button class="btn btn-info" type="button" data-modal="id" onclick="document.getElementById(this.dataset.modal).showModal()">Open</button>"#,
+        ),
+        (
+            DialogType::Checkbox,
+            r#"<label for="id" class="btn btn-info">Open</label>"#,
+        ),
+        (
+            DialogType::AnchorLink,
+            r##"<a href="#id" class="btn btn-info">Open</a>"##,
+        ),
+    ];
+
+    for (modal_type, expected) in triggers {
+        let props = ModalTriggerProps {
+            children: rsx!("Open"),
+            class: String::new(),
+            btn_color: Color::Info,
+            trigger_id: "id".to_string(),
+            modal_type,
+            onclick: None,
+            open: None,
+            attributes: vec![],
+        };
+
+        let result = dioxus_ssr::render_element(ModalTrigger(props));
+        assert_eq!(expected, result);
+    }
+}
+
+#[test]
+fn test_modal_open_signal() {
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        rsx!(
+            ModalTrigger { trigger_id: "id", open, "Open" }
+            Modal { trigger_id: "id", open, "Hello" }
+        )
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<button class="btn btn-info" type="button" aria-haspopup="dialog">Open</button><div role="dialog" aria-modal="true" tabindex="-1" class="modal modal-open" id="id"><div class="modal-box">Hello</div><div class="modal-backdrop"></div></div>"#;
+    assert_eq!(expected, dioxus_ssr::render(&dom));
+}
+
+#[test]
+fn test_modal_closed_signal() {
+    fn app() -> Element {
+        let open = use_signal(|| false);
+        rsx!(
+            Modal { trigger_id: "id", open, "Hello" }
+        )
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    assert_eq!("", dioxus_ssr::render(&dom));
+}
@@ -0,0 +1,145 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::attributes::checked_listeners;
+use crate::classes;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SwapAnimation {
+    #[default]
+    None,
+    Rotate,
+    Flip,
+}
+
+impl Display for SwapAnimation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SwapAnimation::None => write!(f, ""),
+            SwapAnimation::Rotate => write!(f, "swap-rotate"),
+            SwapAnimation::Flip => write!(f, "swap-flip"),
+        }
+    }
+}
+
+/// How the swap changes state
+/// 1. Checkbox - a hidden checkbox inside a label, toggled by clicking it
+/// 2. Class - the `swap-active` class, added here or by our own JS
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SwapType {
+    #[default]
+    Checkbox,
+    Class,
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SwapProps {
+    on: Element,
+    off: Element,
+    indeterminate: Option<Element>,
+    id: Option<String>,
+    name: Option<String>,
+    class: Option<String>,
+    active: Option<bool>,
+    swap_type: Option<SwapType>,
+    swap_animation: Option<SwapAnimation>,
+    /// Drives `active`. Only the checkbox swap writes back to it.
+    bind: Option<Signal<bool>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Swap(props: SwapProps) -> Element {
+    let swap_animation = props.swap_animation.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+    let active = props.bind.map(|bind| bind()).or(props.active);
+
+    match props.swap_type.unwrap_or_default() {
+        SwapType::Checkbox => {
+            let checked = active.and_then(|active| active.then_some("checked"));
+            let listeners = checked_listeners(props.bind, props.onchange);
+            rsx!(
+                label { class: classes!("swap", swap_animation, class), ..props.attributes,
+                    input {
+                        "type": "checkbox",
+                        id: props.id,
+                        name: props.name,
+                        checked,
+                        ..listeners,
+                    }
+                    div { class: "swap-on", {props.on} }
+                    div { class: "swap-off", {props.off} }
+                    if let Some(indeterminate) = props.indeterminate {
+                        div { class: "swap-indeterminate", {indeterminate} }
+                    }
+                }
+            )
+        }
+        SwapType::Class => {
+            let active = if active.unwrap_or(false) {
+                "swap-active"
+            } else {
+                ""
+            };
+            rsx!(
+                div { class: classes!("swap", swap_animation, active, class), id: props.id, ..props.attributes,
+                    div { class: "swap-on", {props.on} }
+                    div { class: "swap-off", {props.off} }
+                    if let Some(indeterminate) = props.indeterminate {
+                        div { class: "swap-indeterminate", {indeterminate} }
+                    }
+                }
+            )
+        }
+    }
+}
+
+#[test]
+fn test_swap_checkbox() {
+    let props = SwapProps {
+        on: rsx!("ON"),
+        off: rsx!("OFF"),
+        indeterminate: None,
+        id: Some("theme".to_string()),
+        name: Some("theme".to_string()),
+        class: Some("test".to_string()),
+        active: Some(true),
+        swap_type: None,
+        swap_animation: Some(SwapAnimation::Rotate),
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<label class="swap swap-rotate test"><input type="checkbox" id="theme" name="theme" checked="checked"/><div class="swap-on">ON</div><div class="swap-off">OFF</div></label>"#;
+    let result = dioxus_ssr::render_element(Swap(props));
+    // println!("{}", result);
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_swap_class_with_indeterminate() {
+    let props = SwapProps {
+        on: rsx!("ON"),
+        off: rsx!("OFF"),
+        indeterminate: Some(rsx!("?")),
+        id: None,
+        name: None,
+        class: None,
+        active: Some(true),
+        swap_type: Some(SwapType::Class),
+        swap_animation: Some(SwapAnimation::Flip),
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<div class="swap swap-flip swap-active"><div class="swap-on">ON</div><div class="swap-off">OFF</div><div class="swap-indeterminate">?</div></div>"#;
+    let result = dioxus_ssr::render_element(Swap(props));
+    // println!("{}", result);
+    assert_eq!(expected, result);
+}
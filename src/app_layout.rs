@@ -129,4 +129,4 @@ pub fn AppLayout(props: AppLayoutProps) -> Element {
             }
         }
     )
-}
\ No newline at end of file
+}
@@ -0,0 +1,87 @@
+use std::rc::Rc;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+/// Hand a composite's extra attributes on to the component it renders.
+///
+/// `rsx!` can only spread attributes onto elements, so composites build
+/// their child's props with this before setting the named props.
+pub(crate) fn forward<B: HasAttributes>(builder: B, attributes: Vec<Attribute>) -> B {
+    attributes.into_iter().fold(builder, |builder, attribute| {
+        builder.push_attribute(
+            attribute.name,
+            attribute.namespace,
+            attribute.value,
+            attribute.volatile,
+        )
+    })
+}
+
+// Listeners can only be created inside a running `VirtualDom`, so the helpers
+// below only add them when a handler or signal was passed. That keeps
+// `dioxus_ssr::render_element` working and the SSR output unchanged.
+
+/// `oninput` and `onchange` for a form control. On input the value is parsed
+/// and written to `bind` before `oninput` is called.
+pub(crate) fn value_listeners<T: FromStr + 'static>(
+    bind: Option<Signal<T>>,
+    oninput: Option<EventHandler<FormEvent>>,
+    onchange: Option<EventHandler<FormEvent>>,
+) -> Vec<Attribute> {
+    let mut listeners = vec![];
+    if bind.is_some() || oninput.is_some() {
+        listeners.push(dioxus_elements::events::oninput(move |event: FormEvent| {
+            if let (Some(mut bind), Ok(value)) = (bind, event.value().parse()) {
+                bind.set(value);
+            }
+            if let Some(oninput) = oninput {
+                oninput.call(event);
+            }
+        }));
+    }
+    listeners.extend(onchange.map(dioxus_elements::events::onchange));
+    listeners
+}
+
+/// `onchange` for a checkbox, writing `checked` to `bind` before calling
+/// `onchange`.
+pub(crate) fn checked_listeners(
+    bind: Option<Signal<bool>>,
+    onchange: Option<EventHandler<FormEvent>>,
+) -> Vec<Attribute> {
+    if bind.is_none() && onchange.is_none() {
+        return vec![];
+    }
+    vec![dioxus_elements::events::onchange(
+        move |event: FormEvent| {
+            if let Some(mut bind) = bind {
+                bind.set(event.checked());
+            }
+            if let Some(onchange) = onchange {
+                onchange.call(event);
+            }
+        },
+    )]
+}
+
+/// An `onmounted` handler for whatever opens `open`, which focuses that
+/// element again once `open` goes back to `false`.
+pub(crate) fn use_return_focus(open: Signal<bool>) -> impl FnMut(MountedEvent) {
+    let mut element = use_signal(|| None::<Rc<MountedData>>);
+    let mut was_open = use_signal(|| false);
+
+    use_effect(move || {
+        let is_open = open();
+        if *was_open.peek() && !is_open {
+            if let Some(element) = element.peek().clone() {
+                spawn(async move {
+                    _ = element.set_focus(true).await;
+                });
+            }
+        }
+        was_open.set(is_open);
+    });
+
+    move |event: MountedEvent| element.set(Some(event.data()))
+}
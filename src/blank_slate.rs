@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
 
-use crate::actions::button::{BtnColor, Button};
+use crate::actions::button::Button;
+use crate::style::Color;
 use dioxus::prelude::*;
 
 #[derive(Props, Clone, PartialEq)]
@@ -49,7 +50,7 @@ pub fn BlankSlate(props: BlankSlateProps) -> Element {
                 Some(pa) => rsx!(
                     div {
                         Button {
-                            btn_color: BtnColor::Primary,
+                            btn_color: Color::Primary,
                             popover_target: "{pa.1}",
                             "{pa.0}"
                         }
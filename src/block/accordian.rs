@@ -9,6 +9,8 @@ pub struct AccordianProps {
     title: String,
     checked: Option<bool>,
     children: Element,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
@@ -16,6 +18,7 @@ pub fn Accordian(props: AccordianProps) -> Element {
     rsx!(
         div {
             class: "collapse collapse-arrow bg-base-200",
+            ..props.attributes,
             input {
                 checked: props.checked,
                 "type": "radio",
@@ -2,6 +2,8 @@
 #![allow(unused_braces)]
 use dioxus::prelude::*;
 
+use crate::classes;
+
 /// DaisyUI color pairs for letter avatars. Each tuple contains the background
 /// color variable and its matching foreground color.
 const AVATAR_COLORS: [(&str, &str); 8] = [
@@ -62,7 +64,7 @@ impl AvatarSize {
         match self {
             AvatarSize::Small => ("24", "24", "w-8 h-8"),
             AvatarSize::Medium => ("48", "48", "w-16 h-16"),
-            AvatarSize::Large => ("96", "96", "w-20 w-20"),
+            AvatarSize::Large => ("96", "96", "w-20 h-20"),
             AvatarSize::ExtraLarge => ("128", "128", "w-32 h-32"),
         }
     }
@@ -75,6 +77,8 @@ pub struct AvatarProps {
     name: Option<String>,
     _email: Option<String>,
     image_src: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
@@ -95,8 +99,8 @@ pub fn Avatar(props: AvatarProps) -> Element {
 
     if let Some(image) = props.image_src {
         rsx!(
-            div { class: "avatar",
-                div { class: "rounded {avatar_size.2}",
+            div { class: "avatar", ..props.attributes,
+                div { class: classes!("rounded", avatar_size.2),
                     img {
                         width: avatar_size.0,
                         height: avatar_size.1,
@@ -108,8 +112,8 @@ pub fn Avatar(props: AvatarProps) -> Element {
     } else {
         match props.avatar_type {
             Some(AvatarType::User) => rsx!(
-                div { class: "avatar",
-                    div { class: "rounded {avatar_size.2}",
+                div { class: "avatar", ..props.attributes,
+                    div { class: classes!("rounded", avatar_size.2),
                         svg {
                             "aria-hidden": true,
                             xmlns: "http://www.w3.org/2000/svg",
@@ -133,8 +137,8 @@ pub fn Avatar(props: AvatarProps) -> Element {
                 }
             ),
             Some(_) => rsx!(
-                div { class: "avatar",
-                    div { class: "rounded {avatar_size.2}",
+                div { class: "avatar", ..props.attributes,
+                    div { class: classes!("rounded", avatar_size.2),
                         svg {
                             "aria-hidden": true,
                             xmlns: "http://www.w3.org/2000/svg",
@@ -161,8 +165,8 @@ pub fn Avatar(props: AvatarProps) -> Element {
                 }
             ),
             None => rsx!(
-                div { class: "avatar",
-                    div { class: "rounded {avatar_size.2}",
+                div { class: "avatar", ..props.attributes,
+                    div { class: classes!("rounded", avatar_size.2),
                         svg {
                             "aria-hidden": true,
                             xmlns: "http://www.w3.org/2000/svg",
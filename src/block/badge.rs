@@ -1,8 +1,9 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier, Size, Variant};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BadgeStyle {
     #[default]
@@ -13,17 +14,13 @@ pub enum BadgeStyle {
     Ghost,
 }
 
-impl Display for BadgeStyle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BadgeStyle::None => write!(f, ""),
-            BadgeStyle::Outline => write!(f, "badge-outline"),
-            BadgeStyle::Dash => write!(f, "badge-dash"),
-            BadgeStyle::Soft => write!(f, "badge-soft"),
-            BadgeStyle::Ghost => write!(f, "badge-ghost"),
-        }
-    }
-}
+legacy_enum!(BadgeStyle => Variant, "badge" {
+    None => Default,
+    Outline => Outline,
+    Dash => Dash,
+    Soft => Soft,
+    Ghost => Ghost,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BadgeColor {
@@ -39,21 +36,17 @@ pub enum BadgeColor {
     Error,
 }
 
-impl Display for BadgeColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BadgeColor::Default => write!(f, ""),
-            BadgeColor::Neutral => write!(f, "badge-neutral"),
-            BadgeColor::Primary => write!(f, "badge-primary"),
-            BadgeColor::Secondary => write!(f, "badge-secondary"),
-            BadgeColor::Accent => write!(f, "badge-accent"),
-            BadgeColor::Info => write!(f, "badge-info"),
-            BadgeColor::Success => write!(f, "badge-success"),
-            BadgeColor::Warning => write!(f, "badge-warning"),
-            BadgeColor::Error => write!(f, "badge-error"),
-        }
-    }
-}
+legacy_enum!(BadgeColor => Color, "badge" {
+    Default => Default,
+    Neutral => Neutral,
+    Primary => Primary,
+    Secondary => Secondary,
+    Accent => Accent,
+    Info => Info,
+    Success => Success,
+    Warning => Warning,
+    Error => Error,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum BadgeSize {
@@ -65,36 +58,34 @@ pub enum BadgeSize {
     Xl,
 }
 
-impl Display for BadgeSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BadgeSize::Md => write!(f, "badge-md"),
-            BadgeSize::Xs => write!(f, "badge-xs"),
-            BadgeSize::Sm => write!(f, "badge-sm"),
-            BadgeSize::Lg => write!(f, "badge-lg"),
-            BadgeSize::Xl => write!(f, "badge-xl"),
-        }
-    }
-}
+legacy_enum!(BadgeSize => Size, "badge" {
+    Xs => Xs,
+    Sm => Sm,
+    Md => Md,
+    Lg => Lg,
+    Xl => Xl,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct BadgeProps {
     children: Element,
     class: Option<String>,
-    badge_style: Option<BadgeStyle>,
-    badge_color: Option<BadgeColor>,
-    badge_size: Option<BadgeSize>,
+    badge_style: Option<Variant>,
+    badge_color: Option<Color>,
+    badge_size: Option<Size>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Badge(props: BadgeProps) -> Element {
-    let badge_style = props.badge_style.unwrap_or_default();
-    let badge_color = props.badge_color.unwrap_or_default();
-    let badge_size = props.badge_size.unwrap_or_default();
+    let badge_style = props.badge_style.unwrap_or_default().class("badge");
+    let badge_color = props.badge_color.unwrap_or_default().class("badge");
+    let badge_size = props.badge_size.unwrap_or_default().class("badge");
     let class = props.class.unwrap_or_default();
 
     rsx!(
-        span { class: "badge {badge_style} {badge_color} {badge_size} {class}", {props.children} }
+        span { class: classes!("badge", badge_style, badge_color, badge_size, class), ..props.attributes, {props.children} }
     )
 }
 
@@ -107,9 +98,10 @@ mod tests {
         let props = BadgeProps {
             children: rsx!("Hello"),
             class: Some("custom".to_string()),
-            badge_style: Some(BadgeStyle::Outline),
-            badge_color: Some(BadgeColor::Primary),
-            badge_size: Some(BadgeSize::Lg),
+            badge_style: Some(Variant::Outline),
+            badge_color: Some(Color::Primary),
+            badge_size: Some(Size::Lg),
+            attributes: vec![],
         };
         let expected =
             r#"<span class="badge badge-outline badge-primary badge-lg custom">Hello</span>"#;
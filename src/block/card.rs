@@ -2,28 +2,27 @@
 
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct CardProps {
     class: Option<String>,
     children: Element,
     popover_target: Option<String>,
     modal_trigger: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Card(props: CardProps) -> Element {
-    let class = if let Some(class) = props.class {
-        class
-    } else {
-        "".to_string()
-    };
-
-    let class = format!("card {}", class);
+    let class = classes!("card", props.class.unwrap_or_default());
 
     rsx!(
         div {
-            class: "{class}",
+            class,
             "data-target": props.popover_target,
+            ..props.attributes,
             {props.children}
         }
     )
@@ -34,12 +33,14 @@ pub struct CardHeadersProps {
     class: Option<String>,
     title: String,
     children: Element,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn CardHeader(props: CardHeadersProps) -> Element {
     rsx!(
-        div { class: "card-header flex items-center {props.class.clone().unwrap_or_default()}",
+        div { class: classes!("card-header flex items-center", props.class.clone().unwrap_or_default()), ..props.attributes,
             h3 { class: "card-title overflow-hidden", "{props.title}" }
             {props.children}
         }
@@ -50,11 +51,13 @@ pub fn CardHeader(props: CardHeadersProps) -> Element {
 pub struct CardBodyProps {
     class: Option<String>,
     children: Element,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn CardBody(props: CardBodyProps) -> Element {
     rsx!(
-        div { class: "card-body {props.class.clone().unwrap_or_default()}", {props.children} }
+        div { class: classes!("card-body", props.class.clone().unwrap_or_default()), ..props.attributes, {props.children} }
     )
 }
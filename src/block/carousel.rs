@@ -0,0 +1,187 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::classes;
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CarouselSnap {
+    #[default]
+    Start,
+    Center,
+    End,
+}
+
+impl Display for CarouselSnap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CarouselSnap::Start => write!(f, "carousel-start"),
+            CarouselSnap::Center => write!(f, "carousel-center"),
+            CarouselSnap::End => write!(f, "carousel-end"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CarouselDirection {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Display for CarouselDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CarouselDirection::Horizontal => write!(f, "carousel-horizontal"),
+            CarouselDirection::Vertical => write!(f, "carousel-vertical"),
+        }
+    }
+}
+
+/// The ids either side of `index`, wrapping around at both ends.
+/// Pass them to `CarouselItem` as `prev_id` and `next_id`.
+pub fn carousel_neighbours(item_ids: &[String], index: usize) -> (String, String) {
+    let len = item_ids.len();
+    if len == 0 {
+        return (String::new(), String::new());
+    }
+    let prev = item_ids[(index + len - 1) % len].clone();
+    let next = item_ids[(index + 1) % len].clone();
+    (prev, next)
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CarouselProps {
+    children: Element,
+    class: Option<String>,
+    snap: Option<CarouselSnap>,
+    direction: Option<CarouselDirection>,
+    indicator_ids: Option<Vec<String>>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Works without JavaScript: navigation and indicators are plain anchors
+/// to the ids of the `CarouselItem`s.
+#[component]
+pub fn Carousel(props: CarouselProps) -> Element {
+    let snap = props.snap.unwrap_or_default();
+    let direction = props.direction.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        div { class: classes!("carousel", snap, direction, class), ..props.attributes, {props.children} }
+        if let Some(item_ids) = props.indicator_ids {
+            CarouselIndicators { item_ids }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CarouselItemProps {
+    children: Element,
+    id: Option<String>,
+    class: Option<String>,
+    full_width: Option<bool>,
+    prev_id: Option<String>,
+    next_id: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn CarouselItem(props: CarouselItemProps) -> Element {
+    let full_width = if props.full_width.unwrap_or(false) {
+        "w-full"
+    } else {
+        ""
+    };
+    let class = props.class.unwrap_or_default();
+
+    if props.prev_id.is_some() || props.next_id.is_some() {
+        rsx!(
+            div { id: props.id, class: classes!("carousel-item relative", full_width, class), ..props.attributes,
+                {props.children}
+                div { class: "absolute left-5 right-5 top-1/2 flex -translate-y-1/2 transform justify-between",
+                    if let Some(prev_id) = props.prev_id {
+                        a { href: "#{prev_id}", class: "btn btn-circle", "❮" }
+                    }
+                    if let Some(next_id) = props.next_id {
+                        a { href: "#{next_id}", class: "btn btn-circle", "❯" }
+                    }
+                }
+            }
+        )
+    } else {
+        rsx!(
+            div { id: props.id, class: classes!("carousel-item", full_width, class), ..props.attributes, {props.children} }
+        )
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CarouselIndicatorsProps {
+    item_ids: Vec<String>,
+    class: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn CarouselIndicators(props: CarouselIndicatorsProps) -> Element {
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        div { class: classes!("flex w-full justify-center gap-2 py-2", class), ..props.attributes,
+            for (index , id) in props.item_ids.iter().enumerate() {
+                a { href: "#{id}", class: "btn btn-xs", "{index + 1}" }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_carousel() {
+        let props = CarouselProps {
+            children: rsx!(
+                CarouselItem { id: "slide1", full_width: true, "One" }
+            ),
+            class: Some("custom".to_string()),
+            snap: Some(CarouselSnap::Center),
+            direction: Some(CarouselDirection::Vertical),
+            indicator_ids: Some(vec!["slide1".to_string(), "slide2".to_string()]),
+            attributes: vec![],
+        };
+        let expected = r##"<div class="carousel carousel-center carousel-vertical custom"><div id="slide1" class="carousel-item w-full">One</div></div><div class="flex w-full justify-center gap-2 py-2"><a href="#slide1" class="btn btn-xs">1</a><a href="#slide2" class="btn btn-xs">2</a></div>"##;
+        let result = dioxus_ssr::render_element(Carousel(props));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_carousel_item_navigation() {
+        let ids = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let (prev_id, next_id) = carousel_neighbours(&ids, 0);
+        assert_eq!(
+            ("c".to_string(), "b".to_string()),
+            (prev_id.clone(), next_id.clone())
+        );
+
+        let props = CarouselItemProps {
+            children: rsx!("A"),
+            id: Some("a".to_string()),
+            class: None,
+            full_width: None,
+            prev_id: Some(prev_id),
+            next_id: Some(next_id),
+            attributes: vec![],
+        };
+        let expected = r##"<div id="a" class="carousel-item relative">A<div class="absolute left-5 right-5 top-1/2 flex -translate-y-1/2 transform justify-between"><a href="#c" class="btn btn-circle">❮</a><a href="#b" class="btn btn-circle">❯</a></div></div>"##;
+        let result = dioxus_ssr::render_element(CarouselItem(props));
+        assert_eq!(result, expected);
+    }
+}
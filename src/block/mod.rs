@@ -2,3 +2,4 @@ pub mod accordian;
 pub mod avatar;
 pub mod badge;
 pub mod card;
+pub mod carousel;
@@ -0,0 +1,102 @@
+//! Building `class` attributes.
+//!
+//! Tokens are split on whitespace, empty ones are dropped and duplicates are
+//! kept once. When a later token sets the same DaisyUI modifier as an earlier
+//! one, e.g. `btn-primary` after `btn-neutral`, the later one wins, so a
+//! caller's `class` pushed last overrides the component's defaults.
+
+use std::fmt::Display;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Classes {
+    tokens: Vec<String>,
+}
+
+impl Classes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, classes: impl Display) -> &mut Self {
+        for token in classes.to_string().split_whitespace() {
+            if self.tokens.iter().any(|existing| existing == token) {
+                continue;
+            }
+            if let Some(group) = modifier_group(token) {
+                self.tokens
+                    .retain(|existing| modifier_group(existing) != Some(group));
+            }
+            self.tokens.push(token.to_string());
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+}
+
+impl Display for Classes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.tokens.join(" "))
+    }
+}
+
+/// Which modifier of which component a token sets, so `badge-lg` and
+/// `badge-sm` share a group but `badge-lg` and `btn-lg` don't.
+fn modifier_group(token: &str) -> Option<(&str, &'static str)> {
+    let (prefix, suffix) = token.rsplit_once('-')?;
+    let group = match suffix {
+        "neutral" | "primary" | "secondary" | "accent" | "info" | "success" | "warning"
+        | "error" => "color",
+        "xs" | "sm" | "md" | "lg" | "xl" => "size",
+        "outline" | "dash" | "soft" | "ghost" | "link" => "variant",
+        _ => return None,
+    };
+    Some((prefix, group))
+}
+
+/// Join class tokens for a `class` attribute, see `Classes`. Gives `None`
+/// when there are no tokens, so the attribute is left out.
+///
+/// ```
+/// use daisy_rsx::classes;
+///
+/// let class = classes!("btn", "btn-neutral", "", "btn-primary mt-2");
+/// assert_eq!(class.as_deref(), Some("btn btn-primary mt-2"));
+/// assert_eq!(classes!("", " "), None);
+/// ```
+#[macro_export]
+macro_rules! classes {
+    ($($classes:expr),* $(,)?) => {{
+        let mut classes = $crate::Classes::new();
+        $(classes.push(&$classes);)*
+        (!classes.is_empty()).then(|| classes.to_string())
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_skips_empty_and_duplicates() {
+        assert_eq!(
+            classes!("badge", "", "  badge  ", "badge-lg").as_deref(),
+            Some("badge badge-lg")
+        );
+    }
+
+    #[test]
+    fn test_later_modifier_wins() {
+        let class = classes!("btn btn-neutral btn-sm", "", "btn-error btn-lg w-full");
+        assert_eq!(class.as_deref(), Some("btn btn-error btn-lg w-full"));
+    }
+
+    #[test]
+    fn test_other_components_dont_conflict() {
+        let class = classes!("alert alert-info", "btn-primary text-sm text-primary");
+        assert_eq!(
+            class.as_deref(),
+            Some("alert alert-info btn-primary text-sm text-primary")
+        );
+    }
+}
@@ -1,6 +1,9 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier};
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum AlertColor {
     #[default]
@@ -23,21 +26,29 @@ impl AlertColor {
     }
 }
 
+legacy_enum!(AlertColor => Color {
+    Default => Info,
+    Warn => Warning,
+    Info => Info,
+    Error => Error,
+    Success => Success,
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct AlertProps {
     children: Element,
     class: Option<String>,
-    alert_color: Option<AlertColor>,
+    alert_color: Option<Color>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Alert(props: AlertProps) -> Element {
-    let alert_color = props.alert_color.unwrap_or_default();
+    let alert_color = props.alert_color.unwrap_or(Color::Info).class("alert");
     let class = props.class.unwrap_or_default();
 
-    let class = format!("{} {}", alert_color.to_string(), class);
-
     rsx!(
-        div { class: "{class}", {props.children} }
+        div { class: classes!("alert", alert_color, class), ..props.attributes, {props.children} }
     )
 }
@@ -3,6 +3,9 @@
 use dioxus::prelude::*;
 use std::fmt::Display;
 
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier, Size};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LoadingSize {
     #[default]
@@ -13,17 +16,13 @@ pub enum LoadingSize {
     Medium,
 }
 
-impl Display for LoadingSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LoadingSize::Default => write!(f, "Loading-sm"),
-            LoadingSize::ExtraSmall => write!(f, "loading-xs"),
-            LoadingSize::Small => write!(f, "loading-sm"),
-            LoadingSize::Large => write!(f, "loading-lg"),
-            LoadingSize::Medium => write!(f, "loading-md"),
-        }
-    }
-}
+legacy_enum!(LoadingSize => Size, "loading" {
+    Default => Sm,
+    ExtraSmall => Xs,
+    Small => Sm,
+    Medium => Md,
+    Large => Lg,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LoadingStyle {
@@ -65,40 +64,57 @@ pub enum LoadingColor {
     Error,
 }
 
-impl Display for LoadingColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LoadingColor::Default => write!(f, "text-neutral"),
-            LoadingColor::Neutral => write!(f, "text-neutral"),
-            LoadingColor::Primary => write!(f, "text-primary"),
-            LoadingColor::Secondary => write!(f, "text-secondary"),
-            LoadingColor::Accent => write!(f, "text-accent"),
-            LoadingColor::Info => write!(f, "text-info"),
-            LoadingColor::Success => write!(f, "text-success"),
-            LoadingColor::Warning => write!(f, "text-waring"),
-            LoadingColor::Error => write!(f, "text-error"),
-        }
-    }
-}
+legacy_enum!(LoadingColor => Color, "text" {
+    Default => Neutral,
+    Neutral => Neutral,
+    Primary => Primary,
+    Secondary => Secondary,
+    Accent => Accent,
+    Info => Info,
+    Success => Success,
+    Warning => Warning,
+    Error => Error,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct LoadingProps {
-    ui_size: Option<LoadingSize>,
+    ui_size: Option<Size>,
     ui_style: Option<LoadingStyle>,
-    ui_color: Option<LoadingColor>,
+    ui_color: Option<Color>,
     pub name: String,
     pub id: Option<String>,
     pub label_class: Option<String>,
     pub value: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Loading(props: LoadingProps) -> Element {
-    let sizes = props.ui_size.unwrap_or_default();
+    let size = props.ui_size.unwrap_or(Size::Sm).class("loading");
     let style = props.ui_style.unwrap_or_default();
-    let color = props.ui_color.unwrap_or_default();
+    let color = props.ui_color.unwrap_or(Color::Neutral).class("text");
+
+    rsx!(span {
+        class: classes!("loading", size, style, color),
+        ..props.attributes
+    })
+}
 
-    let class = format!("{} {} {}", sizes, style, color);
+#[test]
+fn test_loading() {
+    let props = LoadingProps {
+        ui_size: None,
+        ui_style: Some(LoadingStyle::Dots),
+        ui_color: LoadingColor::Warning.into(),
+        name: "loading".to_string(),
+        id: None,
+        label_class: None,
+        value: None,
+        attributes: vec![],
+    };
 
-    rsx!(span { class: "{class}" })
+    let expected = r#"<span class="loading loading-sm loading-dots text-warning"></span>"#;
+    let result = dioxus_ssr::render_element(Loading(props));
+    assert_eq!(result, expected);
 }
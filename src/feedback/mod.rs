@@ -2,3 +2,5 @@ pub mod alert;
 pub mod loading;
 pub mod progress;
 pub mod skeleton;
+pub mod toast;
+pub mod tooltip;
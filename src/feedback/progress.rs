@@ -1,6 +1,8 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
-use std::fmt::Display;
+
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier};
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ProgressColor {
@@ -16,38 +18,37 @@ pub enum ProgressColor {
     Error,
 }
 
-impl Display for ProgressColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ProgressColor::Default => write!(f, "progress-neutral"),
-            ProgressColor::Neutral => write!(f, "progress-neutral"),
-            ProgressColor::Primary => write!(f, "progress-primary"),
-            ProgressColor::Secondary => write!(f, "progress-secondary"),
-            ProgressColor::Accent => write!(f, "progress-accent"),
-            ProgressColor::Info => write!(f, "progress-info"),
-            ProgressColor::Success => write!(f, "progress-success"),
-            ProgressColor::Warning => write!(f, "progress-warning"),
-            ProgressColor::Error => write!(f, "progress-error"),
-        }
-    }
-}
+legacy_enum!(ProgressColor => Color, "progress" {
+    Default => Neutral,
+    Neutral => Neutral,
+    Primary => Primary,
+    Secondary => Secondary,
+    Accent => Accent,
+    Info => Info,
+    Success => Success,
+    Warning => Warning,
+    Error => Error,
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ProgressProps {
-    ui_color: Option<ProgressColor>,
+    ui_color: Option<Color>,
     pub name: String,
     pub id: Option<String>,
     pub value: i16,
     pub max: i16,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Progress(props: ProgressProps) -> Element {
-    let color = props.ui_color.unwrap_or_default();
-    let class = format!("{}", color);
+    let color = props.ui_color.unwrap_or(Color::Neutral).class("progress");
     rsx!(progress {
-        class: "{class}",
+        class: classes!("progress", color),
         value: "{props.value}",
-        max: "{props.max}"
+        max: "{props.max}",
+        ..props.attributes,
     })
 }
 
@@ -65,6 +66,7 @@ pub fn RadialProgress(props: ProgressProps) -> Element {
         div{
             class:"radial-progress bg-primary text-primary-content border-primary border-4",
             style:"--value:{props.value};", aria_valuenow:"{props.value}", role: "progressbar",
+            ..props.attributes,
             "{props.value}%"
         }
     )
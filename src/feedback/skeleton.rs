@@ -0,0 +1,189 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+use crate::attributes::forward;
+use crate::block::avatar::AvatarSize;
+use crate::block::card::{Card, CardBody, CardProps};
+use crate::classes;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonProps {
+    class: Option<String>,
+    /// Tailwind width class, defaults to `w-full`
+    width: Option<String>,
+    /// Tailwind height class, defaults to `h-4`
+    height: Option<String>,
+    circle: Option<bool>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Skeleton(props: SkeletonProps) -> Element {
+    let width = props.width.unwrap_or("w-full".to_string());
+    let height = props.height.unwrap_or("h-4".to_string());
+    let shape = if props.circle.unwrap_or(false) {
+        "rounded-full shrink-0"
+    } else {
+        ""
+    };
+    let class = props.class.unwrap_or_default();
+
+    rsx!(div {
+        class: classes!("skeleton", shape, width, height, class),
+        ..props.attributes
+    })
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonTextProps {
+    class: Option<String>,
+    lines: Option<usize>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Lines of text, the last one shorter like the end of a paragraph.
+#[component]
+pub fn SkeletonText(props: SkeletonTextProps) -> Element {
+    let lines = props.lines.unwrap_or(3);
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        div { class: classes!("flex flex-col gap-2", class), ..props.attributes,
+            for line in 0..lines {
+                if line + 1 == lines && lines > 1 {
+                    Skeleton { width: "w-2/3" }
+                } else {
+                    Skeleton {}
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonAvatarProps {
+    class: Option<String>,
+    avatar_size: Option<AvatarSize>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Same dimensions as an `Avatar` of the given `AvatarSize`.
+#[component]
+pub fn SkeletonAvatar(props: SkeletonAvatarProps) -> Element {
+    let avatar_size = props.avatar_size.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+
+    rsx!(div {
+        class: classes!(
+            "skeleton rounded-full shrink-0",
+            avatar_size.to_string().2,
+            class
+        ),
+        ..props.attributes
+    })
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonCardProps {
+    class: Option<String>,
+    lines: Option<usize>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A `Card` with a title bar and some text, laid out with `CardBody`.
+#[component]
+pub fn SkeletonCard(props: SkeletonCardProps) -> Element {
+    let body = rsx!(
+        CardBody {
+            Skeleton { width: "w-1/2", height: "h-6" }
+            SkeletonText { lines: props.lines }
+        }
+    );
+
+    Card(
+        forward(CardProps::builder(), props.attributes)
+            .class(props.class)
+            .children(body)
+            .build(),
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonTableRowProps {
+    class: Option<String>,
+    columns: usize,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn SkeletonTableRow(props: SkeletonTableRowProps) -> Element {
+    rsx!(
+        tr { class: classes!(props.class.unwrap_or_default()), ..props.attributes,
+            for _ in 0..props.columns {
+                td {
+                    Skeleton {}
+                }
+            }
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_skeleton_text() {
+        let props = SkeletonTextProps {
+            class: None,
+            lines: Some(2),
+            attributes: vec![],
+        };
+        let expected = r#"<div class="flex flex-col gap-2"><div class="skeleton w-full h-4"></div><div class="skeleton w-2/3 h-4"></div></div>"#;
+        let result = dioxus_ssr::render_element(SkeletonText(props));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_skeleton_avatar() {
+        let props = SkeletonAvatarProps {
+            class: None,
+            avatar_size: Some(AvatarSize::Medium),
+            attributes: vec![],
+        };
+        let expected = r#"<div class="skeleton rounded-full shrink-0 w-16 h-16"></div>"#;
+        let result = dioxus_ssr::render_element(SkeletonAvatar(props));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_skeleton_table_row() {
+        let props = SkeletonTableRowProps {
+            class: None,
+            columns: 2,
+            attributes: vec![],
+        };
+        let expected = r#"<tr><td><div class="skeleton w-full h-4"></div></td><td><div class="skeleton w-full h-4"></div></td></tr>"#;
+        let result = dioxus_ssr::render_element(SkeletonTableRow(props));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_skeleton_table_row_class() {
+        let props = SkeletonTableRowProps {
+            class: Some(" opacity-50  h-8 ".to_string()),
+            columns: 1,
+            attributes: vec![],
+        };
+        let expected =
+            r#"<tr class="opacity-50 h-8"><td><div class="skeleton w-full h-4"></div></td></tr>"#;
+        let result = dioxus_ssr::render_element(SkeletonTableRow(props));
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,300 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+use crate::attributes::forward;
+use crate::classes;
+use crate::feedback::alert::AlertColor;
+use crate::style::{Color, Modifier};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ToastPosition {
+    TopStart,
+    TopCenter,
+    TopEnd,
+    MiddleStart,
+    MiddleCenter,
+    MiddleEnd,
+    BottomStart,
+    BottomCenter,
+    #[default]
+    BottomEnd,
+}
+
+impl Display for ToastPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ToastPosition::TopStart => write!(f, "toast-top toast-start"),
+            ToastPosition::TopCenter => write!(f, "toast-top toast-center"),
+            ToastPosition::TopEnd => write!(f, "toast-top toast-end"),
+            ToastPosition::MiddleStart => write!(f, "toast-middle toast-start"),
+            ToastPosition::MiddleCenter => write!(f, "toast-middle toast-center"),
+            ToastPosition::MiddleEnd => write!(f, "toast-middle toast-end"),
+            ToastPosition::BottomStart => write!(f, "toast-bottom toast-start"),
+            ToastPosition::BottomCenter => write!(f, "toast-bottom toast-center"),
+            ToastPosition::BottomEnd => write!(f, "toast-bottom toast-end"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FlashLevel {
+    #[default]
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Display for FlashLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlashLevel::Info => write!(f, "info"),
+            FlashLevel::Success => write!(f, "success"),
+            FlashLevel::Warning => write!(f, "warning"),
+            FlashLevel::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl FromStr for FlashLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(FlashLevel::Info),
+            "success" => Ok(FlashLevel::Success),
+            "warning" => Ok(FlashLevel::Warning),
+            "error" => Ok(FlashLevel::Error),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<FlashLevel> for AlertColor {
+    fn from(level: FlashLevel) -> Self {
+        match level {
+            FlashLevel::Info => AlertColor::Info,
+            FlashLevel::Success => AlertColor::Success,
+            FlashLevel::Warning => AlertColor::Warn,
+            FlashLevel::Error => AlertColor::Error,
+        }
+    }
+}
+
+/// A message set by one request and shown as a toast on the next page.
+///
+/// `to_string()` gives `level:message` with the message percent encoded,
+/// which is safe to store in a cookie or query value. Parse it back with
+/// `str::parse`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Flash {
+    pub level: FlashLevel,
+    pub message: String,
+}
+
+impl Flash {
+    pub fn new(level: FlashLevel, message: impl Into<String>) -> Self {
+        Flash {
+            level,
+            message: message.into(),
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Flash::new(FlashLevel::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Flash::new(FlashLevel::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Flash::new(FlashLevel::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Flash::new(FlashLevel::Error, message)
+    }
+}
+
+impl Display for Flash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.level)?;
+        for byte in self.message.bytes() {
+            if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+                write!(f, "{}", byte as char)?;
+            } else {
+                write!(f, "%{:02X}", byte)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Flash {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (level, encoded) = s.split_once(':').ok_or(())?;
+        let level = level.parse()?;
+
+        let encoded = encoded.as_bytes();
+        let mut bytes = Vec::with_capacity(encoded.len());
+        let mut i = 0;
+        while i < encoded.len() {
+            if encoded[i] == b'%' {
+                let hex = encoded.get(i + 1..i + 3).ok_or(())?;
+                let hex = std::str::from_utf8(hex).map_err(|_| ())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| ())?);
+                i += 3;
+            } else {
+                bytes.push(encoded[i]);
+                i += 1;
+            }
+        }
+        let message = String::from_utf8(bytes).map_err(|_| ())?;
+
+        Ok(Flash { level, message })
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastProps {
+    children: Element,
+    class: Option<String>,
+    position: Option<ToastPosition>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Stack of `Alert`s (or `ToastMessage`s) pinned to a corner of the page.
+#[component]
+pub fn Toast(props: ToastProps) -> Element {
+    let position = props.position.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        div { class: classes!("toast", position, class), ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToastMessageProps {
+    children: Element,
+    class: Option<String>,
+    alert_color: Option<Color>,
+    /// Milliseconds before our JS removes the message
+    auto_hide: Option<u32>,
+    dismissible: Option<bool>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// An alert for use in a `Toast`. The dismiss button and auto hide are
+/// handled by JS through the `data-toast-*` attributes.
+#[component]
+pub fn ToastMessage(props: ToastMessageProps) -> Element {
+    let alert_color = props.alert_color.unwrap_or(Color::Info).class("alert");
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        div {
+            class: classes!("alert", alert_color, class),
+            role: "alert",
+            "data-toast-auto-hide": props.auto_hide,
+            ..props.attributes,
+            span { {props.children} }
+            if props.dismissible.unwrap_or(true) {
+                button {
+                    "type": "button",
+                    class: "btn btn-sm btn-ghost btn-circle",
+                    "aria-label": "Close",
+                    "data-toast-dismiss": true,
+                    "✕"
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FlashToastProps {
+    flashes: Vec<Flash>,
+    class: Option<String>,
+    position: Option<ToastPosition>,
+    auto_hide: Option<u32>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Render the flash messages from the previous request.
+#[component]
+pub fn FlashToast(props: FlashToastProps) -> Element {
+    if props.flashes.is_empty() {
+        return rsx!();
+    }
+
+    let messages = rsx!(
+        for flash in props.flashes {
+            ToastMessage { alert_color: AlertColor::from(flash.level), auto_hide: props.auto_hide,
+                "{flash.message}"
+            }
+        }
+    );
+
+    Toast(
+        forward(ToastProps::builder(), props.attributes)
+            .class(props.class)
+            .position(props.position)
+            .children(messages)
+            .build(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flash_round_trip() {
+        let flash = Flash::warning("Saved; 50% done");
+        let encoded = flash.to_string();
+        assert_eq!(encoded, "warning:Saved%3B%2050%25%20done");
+        assert_eq!(encoded.parse(), Ok(flash));
+        assert_eq!("panic:oops".parse::<Flash>(), Err(()));
+    }
+
+    #[test]
+    fn test_flash_toast() {
+        let props = FlashToastProps {
+            flashes: vec![Flash::success("Saved")],
+            class: None,
+            position: Some(ToastPosition::TopCenter),
+            auto_hide: Some(5000),
+            attributes: vec![],
+        };
+        let expected = r#"<div class="toast toast-top toast-center"><div class="alert alert-success" role="alert" data-toast-auto-hide=5000><span>Saved</span><button type="button" class="btn btn-sm btn-ghost btn-circle" aria-label="Close" data-toast-dismiss=true>✕</button></div></div>"#;
+        let result = dioxus_ssr::render_element(FlashToast(props));
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_flash_toast_attributes() {
+        let props = FlashToastProps {
+            flashes: vec![Flash::error("Failed")],
+            class: None,
+            position: None,
+            auto_hide: None,
+            attributes: vec![
+                Attribute::new("id", "flashes", None, false),
+                Attribute::new("data-turbo-permanent", true, None, false),
+            ],
+        };
+        let expected = r#"<div class="toast toast-bottom toast-end" id="flashes" data-turbo-permanent=true><div class="alert alert-error" role="alert"><span>Failed</span><button type="button" class="btn btn-sm btn-ghost btn-circle" aria-label="Close" data-toast-dismiss=true>✕</button></div></div>"#;
+        let result = dioxus_ssr::render_element(FlashToast(props));
+        assert_eq!(result, expected);
+    }
+}
@@ -1,8 +1,9 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier};
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum ToolTipColor {
     #[default]
@@ -13,32 +14,39 @@ pub enum ToolTipColor {
     Success,
 }
 
-impl Display for ToolTipColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ToolTipColor::Default => write!(f, ""),
-            ToolTipColor::Info => write!(f, "tooltip-info"),
-            ToolTipColor::Warn => write!(f, "tooltip-warning"),
-            ToolTipColor::Error => write!(f, "tooltip-error"),
-            ToolTipColor::Success => write!(f, "tooltip-success"),
-        }
-    }
-}
+legacy_enum!(ToolTipColor => Color, "tooltip" {
+    Default => Default,
+    Warn => Warning,
+    Info => Info,
+    Error => Error,
+    Success => Success,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ToolTipProps {
     text: String,
     children: Element,
     class: Option<String>,
-    alert_color: Option<ToolTipColor>,
+    alert_color: Option<Color>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn ToolTip(props: ToolTipProps) -> Element {
-    let alert_color = props.alert_color.unwrap_or_default();
+    let alert_color = props.alert_color.unwrap_or_default().class("tooltip");
     let class = props.class.unwrap_or_default();
 
     rsx!(
-        div { class: "tooltip {alert_color} {class}", "data-tip": props.text, {props.children} }
+        div { class: classes!("tooltip", alert_color, class), "data-tip": props.text, ..props.attributes, {props.children} }
     )
 }
+
+#[test]
+fn test_tooltip() {
+    let result = dioxus_ssr::render_element(rsx!(
+        ToolTip { text: "Saved", alert_color: ToolTipColor::Warn, class: "ml-2", "Hover" }
+    ));
+    let expected = r#"<div class="tooltip tooltip-warning ml-2" data-tip="Saved">Hover</div>"#;
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,470 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+
+use crate::attributes::value_listeners;
+use crate::classes;
+use crate::style::{Modifier, Size};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A plain calendar date, formatted and parsed as `YYYY-MM-DD` which is
+/// also what `<input type="date">` submits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl CalendarDate {
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        if (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month) {
+            Some(CalendarDate { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        Weekday::from_sunday_index(day_of_week(self.year, self.month, self.day))
+    }
+}
+
+impl Display for CalendarDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for CalendarDate {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '-');
+        let year = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+        let month = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+        let day = parts.next().ok_or(())?.parse().map_err(|_| ())?;
+        CalendarDate::new(year, month, day).ok_or(())
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    fn sunday_index(&self) -> u32 {
+        match self {
+            Weekday::Sunday => 0,
+            Weekday::Monday => 1,
+            Weekday::Tuesday => 2,
+            Weekday::Wednesday => 3,
+            Weekday::Thursday => 4,
+            Weekday::Friday => 5,
+            Weekday::Saturday => 6,
+        }
+    }
+
+    fn from_sunday_index(index: u32) -> Self {
+        match index % 7 {
+            0 => Weekday::Sunday,
+            1 => Weekday::Monday,
+            2 => Weekday::Tuesday,
+            3 => Weekday::Wednesday,
+            4 => Weekday::Thursday,
+            5 => Weekday::Friday,
+            _ => Weekday::Saturday,
+        }
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Weekday::Monday => write!(f, "Mo"),
+            Weekday::Tuesday => write!(f, "Tu"),
+            Weekday::Wednesday => write!(f, "We"),
+            Weekday::Thursday => write!(f, "Th"),
+            Weekday::Friday => write!(f, "Fr"),
+            Weekday::Saturday => write!(f, "Sa"),
+            Weekday::Sunday => write!(f, "Su"),
+        }
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Sakamoto's method, 0 is Sunday.
+fn day_of_week(year: i32, month: u32, day: u32) -> u32 {
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = if month < 3 { year - 1 } else { year };
+    let dow = year + year.div_euclid(4) - year.div_euclid(100)
+        + year.div_euclid(400)
+        + OFFSETS[(month - 1) as usize]
+        + day as i32;
+    dow.rem_euclid(7) as u32
+}
+
+/// The days of the month laid out in weeks, padded with `None`.
+fn month_grid(year: i32, month: u32, first_weekday: Weekday) -> Vec<Vec<Option<u32>>> {
+    let offset = (day_of_week(year, month, 1) + 7 - first_weekday.sunday_index()) % 7;
+    let mut cells: Vec<Option<u32>> = (0..offset).map(|_| None).collect();
+    cells.extend((1..=days_in_month(year, month)).map(Some));
+    let padding = (7 - cells.len() % 7) % 7;
+    cells.extend((0..padding).map(|_| None));
+    cells.chunks(7).map(|week| week.to_vec()).collect()
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CalendarProps {
+    year: i32,
+    /// 1 to 12, the calendar renders nothing for any other month
+    month: u32,
+    class: Option<String>,
+    selected: Option<CalendarDate>,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    disabled_dates: Option<Vec<CalendarDate>>,
+    first_weekday: Option<Weekday>,
+    /// When set the days are radios with this name, otherwise links.
+    name: Option<String>,
+    /// The date is appended to this to build each day link, defaults to `?date=`
+    href: Option<String>,
+    prev_href: Option<String>,
+    next_href: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A month grid that works without JavaScript.
+#[component]
+pub fn Calendar(props: CalendarProps) -> Element {
+    if !(1..=12).contains(&props.month) {
+        return rsx!();
+    }
+
+    let first_weekday = props.first_weekday.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+    let href = props.href.unwrap_or("?date=".to_string());
+    let disabled_dates = props.disabled_dates.unwrap_or_default();
+    let month_name = MONTH_NAMES[props.month as usize - 1];
+
+    let weekdays: Vec<Weekday> = (0..7)
+        .map(|i| Weekday::from_sunday_index(first_weekday.sunday_index() + i))
+        .collect();
+
+    let weeks: Vec<Vec<Option<(CalendarDate, bool, bool)>>> =
+        month_grid(props.year, props.month, first_weekday)
+            .into_iter()
+            .map(|week| {
+                week.into_iter()
+                    .map(|day| {
+                        let date = CalendarDate {
+                            year: props.year,
+                            month: props.month,
+                            day: day?,
+                        };
+                        let disabled = props.min.is_some_and(|min| date < min)
+                            || props.max.is_some_and(|max| date > max)
+                            || disabled_dates.contains(&date);
+                        let selected = props.selected == Some(date);
+                        Some((date, selected, disabled))
+                    })
+                    .collect()
+            })
+            .collect();
+
+    rsx!(
+        div { class: classes!("calendar", class), ..props.attributes,
+            div { class: "flex items-center justify-between mb-2",
+                if let Some(prev_href) = props.prev_href {
+                    a { class: "btn btn-ghost btn-sm", href: "{prev_href}", "aria-label": "Previous month", "‹" }
+                }
+                span { class: "font-semibold", "{month_name} {props.year}" }
+                if let Some(next_href) = props.next_href {
+                    a { class: "btn btn-ghost btn-sm", href: "{next_href}", "aria-label": "Next month", "›" }
+                }
+            }
+            table { class: "table table-xs text-center",
+                thead {
+                    tr {
+                        for weekday in weekdays {
+                            th { "{weekday}" }
+                        }
+                    }
+                }
+                tbody {
+                    for week in weeks {
+                        tr {
+                            for cell in week {
+                                td {
+                                    if let Some((date, selected, disabled)) = cell {
+                                        CalendarDay {
+                                            date,
+                                            selected,
+                                            disabled,
+                                            name: props.name.clone(),
+                                            href: href.clone(),
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct CalendarDayProps {
+    date: CalendarDate,
+    selected: bool,
+    disabled: bool,
+    name: Option<String>,
+    href: String,
+}
+
+#[component]
+fn CalendarDay(props: CalendarDayProps) -> Element {
+    let day = props.date.day;
+    let selected = if props.selected {
+        "btn-primary"
+    } else {
+        "btn-ghost"
+    };
+
+    if let Some(name) = props.name {
+        rsx!(
+            input {
+                "type": "radio",
+                class: classes!("btn btn-xs", selected),
+                name: "{name}",
+                value: "{props.date}",
+                "aria-label": "{day}",
+                checked: props.selected.then_some("checked"),
+                disabled: props.disabled.then_some(true),
+            }
+        )
+    } else if props.disabled {
+        rsx!(
+            span { class: "btn btn-xs btn-ghost btn-disabled", "aria-disabled": "true", "{day}" }
+        )
+    } else {
+        rsx!(
+            a {
+                class: classes!("btn btn-xs", selected),
+                href: "{props.href}{props.date}",
+                "aria-current": props.selected.then_some("date"),
+                "{day}"
+            }
+        )
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DateInputProps {
+    name: String,
+    id: Option<String>,
+    class: Option<String>,
+    label: Option<String>,
+    label_class: Option<String>,
+    value: Option<CalendarDate>,
+    min: Option<CalendarDate>,
+    max: Option<CalendarDate>,
+    required: Option<bool>,
+    disabled: Option<bool>,
+    input_size: Option<Size>,
+    /// The date as a signal, updated as it's typed or picked.
+    bind: Option<Signal<CalendarDate>>,
+    oninput: Option<EventHandler<FormEvent>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = input)]
+    attributes: Vec<Attribute>,
+}
+
+/// The browser's own date picker, for forms where we don't need a `Calendar`.
+#[component]
+pub fn DateInput(props: DateInputProps) -> Element {
+    let input_size = props.input_size.unwrap_or(Size::Sm).class("input");
+    let class = props.class.unwrap_or_default();
+    let value = props.bind.map(|bind| bind()).or(props.value);
+    let mut attributes = props.attributes;
+    attributes.extend(value_listeners(props.bind, props.oninput, props.onchange));
+
+    rsx!(
+        if let Some(l) = props.label {
+            label { class: classes!(props.label_class.unwrap_or_default()),
+                if props.required.is_some() {
+                    "{l} *"
+                } else {
+                    "{l}"
+                }
+            }
+        }
+        input {
+            "type": "date",
+            id: props.id,
+            class: classes!("input input-bordered", input_size, class),
+            name: "{props.name}",
+            value: value.map(|d| d.to_string()),
+            min: props.min.map(|d| d.to_string()),
+            max: props.max.map(|d| d.to_string()),
+            required: props.required,
+            disabled: props.disabled,
+            ..attributes,
+        }
+    )
+}
+
+#[test]
+fn test_calendar_dates() {
+    assert_eq!(days_in_month(2024, 2), 29);
+    assert_eq!(days_in_month(1900, 2), 28);
+    assert_eq!(
+        CalendarDate::new(2024, 5, 1).map(|d| d.weekday()),
+        Some(Weekday::Wednesday)
+    );
+    assert_eq!(
+        "2024-05-01".parse(),
+        Ok(CalendarDate::new(2024, 5, 1).unwrap())
+    );
+    assert_eq!("2024-02-30".parse::<CalendarDate>(), Err(()));
+    assert_eq!(
+        CalendarDate::new(2024, 5, 1).unwrap().to_string(),
+        "2024-05-01"
+    );
+}
+
+#[test]
+fn test_calendar_links() {
+    // February 2021 starts on a Monday and fills exactly four weeks
+    let props = CalendarProps {
+        year: 2021,
+        month: 2,
+        class: None,
+        selected: CalendarDate::new(2021, 2, 3),
+        min: CalendarDate::new(2021, 2, 2),
+        max: None,
+        disabled_dates: Some(vec![CalendarDate::new(2021, 2, 5).unwrap()]),
+        first_weekday: None,
+        name: None,
+        href: Some("/audit?date=".to_string()),
+        prev_href: None,
+        next_href: None,
+        attributes: vec![],
+    };
+    let result = dioxus_ssr::render_element(Calendar(props));
+    assert!(result.contains(r#"<span class="font-semibold">February 2021</span>"#));
+    assert!(result.contains("<thead><tr><th>Mo</th><th>Tu</th>"));
+    assert!(result.contains(r#"<tbody><tr><td><span class="btn btn-xs btn-ghost btn-disabled" aria-disabled="true">1</span></td><td><a class="btn btn-xs btn-ghost" href="/audit?date=2021-02-02">2</a></td><td><a class="btn btn-xs btn-primary" href="/audit?date=2021-02-03" aria-current="date">3</a></td>"#));
+    assert!(result.contains(
+        r#"<span class="btn btn-xs btn-ghost btn-disabled" aria-disabled="true">5</span>"#
+    ));
+    assert_eq!(result.matches("<tr>").count(), 5);
+}
+
+#[test]
+fn test_calendar_radios() {
+    // May 2024 starts on a Wednesday, so with Sunday first there are 3 blanks
+    let props = CalendarProps {
+        year: 2024,
+        month: 5,
+        class: None,
+        selected: None,
+        min: None,
+        max: None,
+        disabled_dates: None,
+        first_weekday: Some(Weekday::Sunday),
+        name: Some("day".to_string()),
+        href: None,
+        prev_href: None,
+        next_href: None,
+        attributes: vec![],
+    };
+    let result = dioxus_ssr::render_element(Calendar(props));
+    assert!(result.contains(r#"<tbody><tr><td></td><td></td><td></td><td><input type="radio" class="btn btn-xs btn-ghost" name="day" value="2024-05-01" aria-label="1"/></td>"#));
+}
+
+#[test]
+fn test_calendar_invalid_month() {
+    for month in [0, 13] {
+        let props = CalendarProps {
+            year: 2024,
+            month,
+            class: None,
+            selected: None,
+            min: None,
+            max: None,
+            disabled_dates: None,
+            first_weekday: None,
+            name: None,
+            href: None,
+            prev_href: None,
+            next_href: None,
+            attributes: vec![],
+        };
+        assert_eq!(dioxus_ssr::render_element(Calendar(props)), "");
+    }
+}
+
+#[test]
+fn test_date_input() {
+    let props = DateInputProps {
+        name: "from".to_string(),
+        id: None,
+        class: None,
+        label: Some("From".to_string()),
+        label_class: None,
+        value: CalendarDate::new(2024, 5, 1),
+        min: None,
+        max: CalendarDate::new(2024, 12, 31),
+        required: None,
+        disabled: None,
+        input_size: None,
+        bind: None,
+        oninput: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<label>From</label><input type="date" class="input input-bordered input-sm" name="from" value="2024-05-01" max="2024-12-31"/>"#;
+    let result = dioxus_ssr::render_element(DateInput(props));
+    assert_eq!(result, expected);
+}
@@ -1,27 +1,26 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
+use crate::attributes::checked_listeners;
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier, Size};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CheckBoxScheme {
     #[default]
     Default,
     Primary,
+    /// DaisyUI has no outline checkbox, so this renders like `Default`.
     Outline,
     Danger,
 }
 
-impl Display for CheckBoxScheme {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CheckBoxScheme::Default => write!(f, "checkbox-default"),
-            CheckBoxScheme::Primary => write!(f, "checkbox-primary"),
-            CheckBoxScheme::Outline => write!(f, "checkbox-outline"),
-            CheckBoxScheme::Danger => write!(f, "checkbox-warning"),
-        }
-    }
-}
+legacy_enum!(CheckBoxScheme => Color, "checkbox" {
+    Default => Default,
+    Primary => Primary,
+    Outline => Default,
+    Danger => Error,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum CheckBoxSize {
@@ -33,17 +32,13 @@ pub enum CheckBoxSize {
     Medium,
 }
 
-impl Display for CheckBoxSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CheckBoxSize::Default => write!(f, "checkbox-sm"),
-            CheckBoxSize::Small => write!(f, "checkbox-sm"),
-            CheckBoxSize::ExtraSmall => write!(f, "checkbox-xs"),
-            CheckBoxSize::Large => write!(f, "checkbox-lg"),
-            CheckBoxSize::Medium => write!(f, "checkbox-md"),
-        }
-    }
-}
+legacy_enum!(CheckBoxSize => Size, "checkbox" {
+    Default => Sm,
+    Small => Sm,
+    ExtraSmall => Xs,
+    Large => Lg,
+    Medium => Md,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct CheckBoxProps {
@@ -53,28 +48,38 @@ pub struct CheckBoxProps {
     class: Option<String>,
     name: String,
     value: String,
-    checkbox_size: Option<CheckBoxSize>,
-    checkbox_scheme: Option<CheckBoxScheme>,
+    checkbox_size: Option<Size>,
+    checkbox_scheme: Option<Color>,
+    /// Keeps `checked` in sync with a signal.
+    bind: Option<Signal<bool>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = input)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn CheckBox(props: CheckBoxProps) -> Element {
-    let checkbox_scheme = props.checkbox_scheme.unwrap_or_default();
-    let checkbox_size = props.checkbox_size.unwrap_or_default();
+    let checkbox_scheme = props.checkbox_scheme.unwrap_or_default().class("checkbox");
+    let checkbox_size = props.checkbox_size.unwrap_or(Size::Sm).class("checkbox");
     let class = props.class.unwrap_or_default();
 
     let checked = props
-        .checked
+        .bind
+        .map(|bind| bind())
+        .or(props.checked)
         .and_then(|checked| checked.then_some("checked"));
+    let mut attributes = props.attributes;
+    attributes.extend(checked_listeners(props.bind, props.onchange));
 
     rsx!(
         input {
             "type": "checkbox",
-            class: "checkbox {class} {checkbox_scheme} {checkbox_size}",
+            class: classes!("checkbox", checkbox_scheme, checkbox_size, class),
             id: props.id,
             name: props.name,
             value: props.value,
             checked,
+            ..attributes,
             {props.children}
         }
     )
@@ -88,11 +93,14 @@ fn test_check_box() {
         value: "value".to_string(),
         checked: Some(true),
         class: Some("custom".to_string()),
-        checkbox_size: Some(CheckBoxSize::Large),
-        checkbox_scheme: Some(CheckBoxScheme::Danger),
+        checkbox_size: Some(Size::Lg),
+        checkbox_scheme: CheckBoxScheme::Danger.into(),
         id: Some("id".to_string()),
+        bind: None,
+        onchange: None,
+        attributes: vec![],
     };
-    let expected = r#"<input type="checkbox" class="checkbox custom checkbox-warning checkbox-lg" id="id" name="name" value="value" checked="checked"></input>"#;
+    let expected = r#"<input type="checkbox" class="checkbox checkbox-error checkbox-lg custom" id="id" name="name" value="value" checked="checked"></input>"#;
     let result = dioxus_ssr::render_element(CheckBox(props));
     // println!("{}", result);
     assert_eq!(result, expected);
@@ -109,8 +117,12 @@ fn test_check_box_default() {
         checkbox_size: None,
         checkbox_scheme: None,
         id: None,
+        bind: None,
+        onchange: None,
+        attributes: vec![],
     };
-    let expected = r#"<input type="checkbox" class="checkbox  checkbox-default checkbox-sm" name="name" value="value"></input>"#;
+    let expected =
+        r#"<input type="checkbox" class="checkbox checkbox-sm" name="name" value="value"></input>"#;
     let result = dioxus_ssr::render_element(CheckBox(props));
     // println!("{}", result);
     assert_eq!(result, expected);
@@ -127,9 +139,33 @@ fn test_check_box_checked_false() {
         checkbox_size: None,
         checkbox_scheme: None,
         id: None,
+        bind: None,
+        onchange: None,
+        attributes: vec![],
     };
-    let expected = r#"<input type="checkbox" class="checkbox  checkbox-default checkbox-sm" name="name" value="value"></input>"#;
+    let expected =
+        r#"<input type="checkbox" class="checkbox checkbox-sm" name="name" value="value"></input>"#;
     let result = dioxus_ssr::render_element(CheckBox(props));
     // println!("{}", result);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_check_box_bind() {
+    fn app() -> Element {
+        let agreed = Signal::new(true);
+        rsx!(CheckBox {
+            name: "terms",
+            value: "yes",
+            checked: false,
+            bind: agreed,
+            onchange: move |_| {}
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<input type="checkbox" class="checkbox checkbox-sm" name="terms" value="yes" checked="checked"></input>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
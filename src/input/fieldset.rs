@@ -3,6 +3,8 @@
 
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct FieldsetProps {
     legend: String,
@@ -10,6 +12,8 @@ pub struct FieldsetProps {
     class: Option<String>,
     legend_class: Option<String>,
     help_text: Option<String>,
+    #[props(extends = GlobalAttributes, extends = fieldset)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
@@ -18,8 +22,8 @@ pub fn Fieldset(props: FieldsetProps) -> Element {
     let legend_class = props.legend_class.unwrap_or_default();
 
     rsx!(
-        fieldset { class: "fieldset {class}",
-            legend { class: "fieldset-legend {legend_class}", "{props.legend}" }
+        fieldset { class: classes!("fieldset", class), ..props.attributes,
+            legend { class: classes!("fieldset-legend", legend_class), "{props.legend}" }
             {props.children}
             match props.help_text {
                 Some(help) => rsx!( p { class: "label", "{help}" } ),
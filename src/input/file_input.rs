@@ -1,8 +1,9 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier, Size, Variant};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FileInputStyle {
     #[default]
@@ -10,14 +11,10 @@ pub enum FileInputStyle {
     Ghost,
 }
 
-impl Display for FileInputStyle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            FileInputStyle::Default => write!(f, ""),
-            FileInputStyle::Ghost => write!(f, "file-input-ghost"),
-        }
-    }
-}
+legacy_enum!(FileInputStyle => Variant, "file-input" {
+    Default => Default,
+    Ghost => Ghost,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FileInputColor {
@@ -33,21 +30,17 @@ pub enum FileInputColor {
     Error,
 }
 
-impl Display for FileInputColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            FileInputColor::Default => write!(f, ""),
-            FileInputColor::Neutral => write!(f, "file-input-neutral"),
-            FileInputColor::Primary => write!(f, "file-input-primary"),
-            FileInputColor::Secondary => write!(f, "file-input-secondary"),
-            FileInputColor::Accent => write!(f, "file-input-accent"),
-            FileInputColor::Info => write!(f, "file-input-info"),
-            FileInputColor::Success => write!(f, "file-input-success"),
-            FileInputColor::Warning => write!(f, "file-input-warning"),
-            FileInputColor::Error => write!(f, "file-input-error"),
-        }
-    }
-}
+legacy_enum!(FileInputColor => Color, "file-input" {
+    Default => Default,
+    Neutral => Neutral,
+    Primary => Primary,
+    Secondary => Secondary,
+    Accent => Accent,
+    Info => Info,
+    Success => Success,
+    Warning => Warning,
+    Error => Error,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum FileInputSize {
@@ -59,17 +52,13 @@ pub enum FileInputSize {
     Xl,
 }
 
-impl Display for FileInputSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            FileInputSize::Md => write!(f, "file-input-md"),
-            FileInputSize::Xs => write!(f, "file-input-xs"),
-            FileInputSize::Sm => write!(f, "file-input-sm"),
-            FileInputSize::Lg => write!(f, "file-input-lg"),
-            FileInputSize::Xl => write!(f, "file-input-xl"),
-        }
-    }
-}
+legacy_enum!(FileInputSize => Size, "file-input" {
+    Xs => Xs,
+    Sm => Sm,
+    Md => Md,
+    Lg => Lg,
+    Xl => Xl,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct FileInputProps {
@@ -79,18 +68,32 @@ pub struct FileInputProps {
     accept: Option<String>,
     multiple: Option<bool>,
     disabled: Option<bool>,
-    file_input_style: Option<FileInputStyle>,
-    file_input_color: Option<FileInputColor>,
-    file_input_size: Option<FileInputSize>,
+    file_input_style: Option<Variant>,
+    file_input_color: Option<Color>,
+    file_input_size: Option<Size>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = input)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn FileInput(props: FileInputProps) -> Element {
-    let style = props.file_input_style.unwrap_or_default();
-    let color = props.file_input_color.unwrap_or_default();
-    let size = props.file_input_size.unwrap_or_default();
+    let style = props
+        .file_input_style
+        .unwrap_or_default()
+        .class("file-input");
+    let color = props
+        .file_input_color
+        .unwrap_or_default()
+        .class("file-input");
+    let size = props
+        .file_input_size
+        .unwrap_or_default()
+        .class("file-input");
     let class = props.class.unwrap_or_default();
     let disabled = props.disabled.filter(|&d| d);
+    let mut attributes = props.attributes;
+    attributes.extend(props.onchange.map(onchange));
 
     rsx!(
         input {
@@ -100,7 +103,8 @@ pub fn FileInput(props: FileInputProps) -> Element {
             accept: props.accept,
             multiple: props.multiple,
             disabled,
-            class: "file-input {class} {style} {color} {size}",
+            class: classes!("file-input", style, color, size, class),
+            ..attributes,
         }
     )
 }
@@ -118,16 +122,20 @@ mod tests {
             accept: Some("image/*".to_string()),
             multiple: Some(true),
             disabled: Some(false),
-            file_input_style: Some(FileInputStyle::Ghost),
-            file_input_color: Some(FileInputColor::Primary),
-            file_input_size: Some(FileInputSize::Lg),
+            file_input_style: Some(Variant::Ghost),
+            file_input_color: Some(Color::Primary),
+            file_input_size: Some(Size::Lg),
+            onchange: None,
+            attributes: vec![],
         };
 
         let result = dioxus_ssr::render_element(FileInput(props));
         assert!(result.contains("file-input-ghost"));
         assert!(result.contains("file-input-primary"));
         assert!(result.contains("file-input-lg"));
-        assert!(result.contains("class=\"file-input custom"));
+        assert!(result.contains(
+            "class=\"file-input file-input-ghost file-input-primary file-input-lg custom\""
+        ));
     }
 
     #[test]
@@ -142,6 +150,8 @@ mod tests {
             file_input_style: None,
             file_input_color: None,
             file_input_size: None,
+            onchange: None,
+            attributes: vec![],
         };
 
         let result = dioxus_ssr::render_element(FileInput(props));
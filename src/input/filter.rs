@@ -0,0 +1,126 @@
+#![allow(non_snake_case)]
+
+use dioxus::prelude::*;
+
+use crate::classes;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FilterProps {
+    name: String,
+    /// `(value, label)` for each chip
+    options: Vec<(String, String)>,
+    #[props(default)]
+    selected: Vec<String>,
+    /// Checkboxes so more than one chip can be selected
+    multiple: Option<bool>,
+    /// Where the form is submitted, defaults to the current page
+    action: Option<String>,
+    class: Option<String>,
+    submit_text: Option<String>,
+    onsubmit: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = form)]
+    attributes: Vec<Attribute>,
+}
+
+/// A GET form of chips, so list pages can be filtered without JS.
+///
+/// In single mode the reset chip submits an empty value. In multiple mode
+/// it's a link back to `action` with no query.
+#[component]
+pub fn Filter(props: FilterProps) -> Element {
+    let submit_text = props.submit_text.unwrap_or("Apply".to_string());
+    let mut attributes = props.attributes;
+    attributes.extend(props.onsubmit.map(onsubmit));
+
+    if props.multiple.unwrap_or(false) {
+        let reset_href = props.action.clone().unwrap_or("?".to_string());
+        rsx!(
+            form { class: classes!(props.class.unwrap_or_default()), method: "get", action: props.action, ..attributes,
+                div { class: "flex flex-wrap gap-1",
+                    a { class: "btn btn-square", href: "{reset_href}", "aria-label": "Reset", "×" }
+                    for (value , label) in props.options {
+                        input {
+                            class: "btn",
+                            "type": "checkbox",
+                            name: "{props.name}",
+                            value: "{value}",
+                            "aria-label": "{label}",
+                            checked: props.selected.contains(&value).then_some("checked"),
+                        }
+                    }
+                }
+                button { class: "btn btn-primary", "type": "submit", "{submit_text}" }
+            }
+        )
+    } else {
+        rsx!(
+            form { class: classes!(props.class.unwrap_or_default()), method: "get", action: props.action, ..attributes,
+                div { class: "filter",
+                    input {
+                        class: "btn filter-reset",
+                        "type": "radio",
+                        name: "{props.name}",
+                        value: "",
+                        "aria-label": "×",
+                        checked: props.selected.is_empty().then_some("checked"),
+                    }
+                    for (value , label) in props.options {
+                        input {
+                            class: "btn",
+                            "type": "radio",
+                            name: "{props.name}",
+                            value: "{value}",
+                            "aria-label": "{label}",
+                            checked: props.selected.contains(&value).then_some("checked"),
+                        }
+                    }
+                }
+                button { class: "btn btn-primary", "type": "submit", "{submit_text}" }
+            }
+        )
+    }
+}
+
+#[test]
+fn test_filter() {
+    let props = FilterProps {
+        name: "visibility".to_string(),
+        options: vec![
+            ("private".to_string(), "Private".to_string()),
+            ("team".to_string(), "Team".to_string()),
+        ],
+        selected: vec!["team".to_string()],
+        multiple: None,
+        action: Some("/prompts".to_string()),
+        class: None,
+        submit_text: None,
+        onsubmit: None,
+        attributes: vec![],
+    };
+    let expected = r#"<form method="get" action="/prompts"><div class="filter"><input class="btn filter-reset" type="radio" name="visibility" value="" aria-label="×"/><input class="btn" type="radio" name="visibility" value="private" aria-label="Private"/><input class="btn" type="radio" name="visibility" value="team" aria-label="Team" checked="checked"/></div><button class="btn btn-primary" type="submit">Apply</button></form>"#;
+    let result = dioxus_ssr::render_element(Filter(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_filter_multiple() {
+    let props = FilterProps {
+        name: "type".to_string(),
+        options: vec![
+            ("pdf".to_string(), "PDF".to_string()),
+            ("txt".to_string(), "Text".to_string()),
+        ],
+        selected: vec!["pdf".to_string(), "txt".to_string()],
+        multiple: Some(true),
+        action: Some("/documents".to_string()),
+        class: None,
+        submit_text: Some("Filter".to_string()),
+        onsubmit: None,
+        attributes: vec![],
+    };
+    let expected = r#"<form method="get" action="/documents"><div class="flex flex-wrap gap-1"><a class="btn btn-square" href="/documents" aria-label="Reset">×</a><input class="btn" type="checkbox" name="type" value="pdf" aria-label="PDF" checked="checked"/><input class="btn" type="checkbox" name="type" value="txt" aria-label="Text" checked="checked"/></div><button class="btn btn-primary" type="submit">Filter</button></form>"#;
+    let result = dioxus_ssr::render_element(Filter(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
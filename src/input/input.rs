@@ -3,6 +3,10 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::attributes::value_listeners;
+use crate::classes;
+use crate::style::{legacy_enum, Modifier, Size};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum InputType {
     #[default]
@@ -10,6 +14,11 @@ pub enum InputType {
     Number,
     Email,
     Password,
+    Date,
+    DateTimeLocal,
+    Month,
+    Week,
+    Time,
 }
 
 impl Display for InputType {
@@ -19,6 +28,11 @@ impl Display for InputType {
             InputType::Number => write!(f, "number"),
             InputType::Email => write!(f, "email"),
             InputType::Password => write!(f, "password"),
+            InputType::Date => write!(f, "date"),
+            InputType::DateTimeLocal => write!(f, "datetime-local"),
+            InputType::Month => write!(f, "month"),
+            InputType::Week => write!(f, "week"),
+            InputType::Time => write!(f, "time"),
         }
     }
 }
@@ -33,22 +47,18 @@ pub enum InputSize {
     Medium,
 }
 
-impl Display for InputSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InputSize::Default => write!(f, "input-sm"),
-            InputSize::ExtraSmall => write!(f, "input-xs"),
-            InputSize::Small => write!(f, "input-sm"),
-            InputSize::Large => write!(f, "input-lg"),
-            InputSize::Medium => write!(f, "input-md"),
-        }
-    }
-}
+legacy_enum!(InputSize => Size, "input" {
+    Default => Sm,
+    ExtraSmall => Xs,
+    Small => Sm,
+    Medium => Md,
+    Large => Lg,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct InputProps {
     input_type: Option<InputType>,
-    input_size: Option<InputSize>,
+    input_size: Option<Size>,
     pub name: String,
     pub id: Option<String>,
     pub label_class: Option<String>,
@@ -60,27 +70,36 @@ pub struct InputProps {
     pub required: Option<bool>,
     pub disabled: Option<bool>,
     pub readonly: Option<bool>,
+    /// Two-way binding for client-side apps, used instead of `value`.
+    pub bind: Option<Signal<String>>,
+    pub oninput: Option<EventHandler<FormEvent>>,
+    pub onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = input)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Input(props: InputProps) -> Element {
     let input_type = props.input_type.unwrap_or_default();
-    let input_size = props.input_size.unwrap_or_default();
+    let input_size = props.input_size.unwrap_or(Size::Sm).class("input");
+    let value = props.bind.map(|bind| bind()).or(props.value);
+    let mut attributes = props.attributes;
+    attributes.extend(value_listeners(props.bind, props.oninput, props.onchange));
 
     rsx!(
         match (props.label, props.required) {
             (Some(l), Some(_)) => rsx! {
-                label { class: props.label_class, "{l} *" }
+                label { class: classes!(props.label_class.unwrap_or_default()), "{l} *" }
             },
             (Some(l), None) => rsx! {
-                label { class: props.label_class, "{l}" }
+                label { class: classes!(props.label_class.unwrap_or_default()), "{l}" }
             },
             (None, _) => rsx! {},
         }
         input {
             id: props.id,
-            class: "input input-bordered {input_size}",
-            value: props.value,
+            class: classes!("input input-bordered", input_size),
+            value,
             required: props.required,
             disabled: props.disabled,
             readonly: props.readonly,
@@ -88,6 +107,7 @@ pub fn Input(props: InputProps) -> Element {
             placeholder: props.placeholder,
             step: props.step,
             "type": "{input_type}",
+            ..attributes,
         }
         if let Some(l) = props.help_text {
             label {
@@ -96,3 +116,55 @@ pub fn Input(props: InputProps) -> Element {
         }
     )
 }
+
+#[test]
+fn test_input_attributes() {
+    let props = InputProps {
+        input_type: Some(InputType::Number),
+        input_size: None,
+        name: "age".to_string(),
+        id: None,
+        label_class: None,
+        value: None,
+        label: None,
+        help_text: None,
+        placeholder: None,
+        step: None,
+        required: None,
+        disabled: None,
+        readonly: None,
+        bind: None,
+        oninput: None,
+        onchange: None,
+        attributes: vec![
+            Attribute::new("autocomplete", "off", None, false),
+            Attribute::new("pattern", "[0-9]*", None, false),
+            Attribute::new("min", "18", None, false),
+            Attribute::new("max", "99", None, false),
+        ],
+    };
+
+    let expected = r#"<input class="input input-bordered input-sm" name="age" type="number" autocomplete="off" pattern="[0-9]*" min="18" max="99"/>"#;
+    let result = dioxus_ssr::render_element(Input(props));
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn test_input_bind() {
+    fn app() -> Element {
+        let name = Signal::new("Ada".to_string());
+        rsx!(Input {
+            name: "name",
+            value: "ignored",
+            bind: name,
+            oninput: move |_| {}
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected =
+        r#"<input class="input input-bordered input-sm" value="Ada" name="name" type="text"/>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
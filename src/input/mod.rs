@@ -1,8 +1,16 @@
+pub mod calendar;
 pub mod check_box;
 pub mod fieldset;
 pub mod file_input;
+pub mod filter;
+#[allow(clippy::module_inception)]
 pub mod input;
+pub mod radio;
+pub mod range;
+pub mod rating;
+pub mod select;
 pub mod text_area;
+pub mod toggle;
 
 pub use input::Input;
 pub use input::InputSize;
@@ -0,0 +1,173 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::attributes::{forward, value_listeners};
+use crate::classes;
+use crate::input::fieldset::{Fieldset, FieldsetProps};
+use crate::style::{Color, Modifier, Size};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioProps {
+    id: Option<String>,
+    checked: Option<bool>,
+    class: Option<String>,
+    name: String,
+    value: String,
+    disabled: Option<bool>,
+    radio_size: Option<Size>,
+    radio_color: Option<Color>,
+    /// The group's selected value, this radio is checked when it matches `value`.
+    bind: Option<Signal<String>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = input)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Radio(props: RadioProps) -> Element {
+    let radio_color = props.radio_color.unwrap_or_default().class("radio");
+    let radio_size = props.radio_size.unwrap_or_default().class("radio");
+    let class = props.class.unwrap_or_default();
+    let disabled = props.disabled.filter(|&d| d);
+
+    let checked = props
+        .bind
+        .map(|bind| bind() == props.value)
+        .or(props.checked)
+        .and_then(|checked| checked.then_some("checked"));
+    let mut attributes = props.attributes;
+    attributes.extend(value_listeners(props.bind, None, props.onchange));
+
+    rsx!(
+        input {
+            "type": "radio",
+            class: classes!("radio", radio_color, radio_size, class),
+            id: props.id,
+            name: props.name,
+            value: props.value,
+            checked,
+            disabled,
+            ..attributes,
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RadioGroupProps {
+    name: String,
+    legend: String,
+    /// `(value, label, description)` for each radio
+    options: Vec<(String, String, Option<String>)>,
+    selected: Option<String>,
+    class: Option<String>,
+    help_text: Option<String>,
+    disabled: Option<bool>,
+    radio_size: Option<Size>,
+    radio_color: Option<Color>,
+    /// The selected value as a signal, for client-side apps.
+    bind: Option<Signal<String>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A set of radios under a `Fieldset` legend, with the `selected` value checked.
+#[component]
+pub fn RadioGroup(props: RadioGroupProps) -> Element {
+    let options = rsx!(
+        for (value , label , description) in props.options {
+            label { class: "label",
+                Radio {
+                    name: props.name.clone(),
+                    checked: props.selected.as_ref() == Some(&value),
+                    value,
+                    disabled: props.disabled,
+                    radio_size: props.radio_size,
+                    radio_color: props.radio_color,
+                    bind: props.bind,
+                    onchange: props.onchange,
+                }
+                "{label}"
+            }
+            if let Some(description) = description {
+                p { class: "label ml-8", "{description}" }
+            }
+        }
+    );
+
+    Fieldset(
+        forward(FieldsetProps::builder(), props.attributes)
+            .legend(props.legend)
+            .class(props.class)
+            .help_text(props.help_text)
+            .children(options)
+            .build(),
+    )
+}
+
+#[test]
+fn test_radio() {
+    let props = RadioProps {
+        name: "name".to_string(),
+        value: "value".to_string(),
+        checked: Some(true),
+        class: Some("custom".to_string()),
+        disabled: None,
+        radio_size: Some(Size::Sm),
+        radio_color: Some(Color::Primary),
+        id: Some("id".to_string()),
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<input type="radio" class="radio radio-primary radio-sm custom" id="id" name="name" value="value" checked="checked"/>"#;
+    let result = dioxus_ssr::render_element(Radio(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_radio_group() {
+    let props = RadioGroupProps {
+        name: "visibility".to_string(),
+        legend: "Visibility".to_string(),
+        options: vec![
+            (
+                "private".to_string(),
+                "Private".to_string(),
+                Some("Only you".to_string()),
+            ),
+            ("team".to_string(), "Team".to_string(), None),
+        ],
+        selected: Some("team".to_string()),
+        class: None,
+        help_text: None,
+        disabled: None,
+        radio_size: None,
+        radio_color: None,
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<fieldset class="fieldset"><legend class="fieldset-legend">Visibility</legend><label class="label"><input type="radio" class="radio radio-md" name="visibility" value="private"/>Private</label><p class="label ml-8">Only you</p><label class="label"><input type="radio" class="radio radio-md" name="visibility" value="team" checked="checked"/>Team</label></fieldset>"#;
+    let result = dioxus_ssr::render_element(RadioGroup(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_radio_bind() {
+    fn app() -> Element {
+        let plan = Signal::new("pro".to_string());
+        rsx!(
+            Radio { name: "plan", value: "free", checked: true, bind: plan }
+            Radio { name: "plan", value: "pro", bind: plan }
+        )
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<input type="radio" class="radio radio-md" name="plan" value="free"/><input type="radio" class="radio radio-md" name="plan" value="pro" checked="checked"/>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
@@ -1,8 +1,10 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
+use crate::attributes::value_listeners;
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier};
+
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum RangeColor {
     #[default]
@@ -13,17 +15,13 @@ pub enum RangeColor {
     Success,
 }
 
-impl Display for RangeColor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            RangeColor::Default => write!(f, ""),
-            RangeColor::Info => write!(f, "range-info"),
-            RangeColor::Warn => write!(f, "range-warning"),
-            RangeColor::Error => write!(f, "range-error"),
-            RangeColor::Success => write!(f, "range-success"),
-        }
-    }
-}
+legacy_enum!(RangeColor => Color, "range" {
+    Default => Default,
+    Warn => Warning,
+    Info => Info,
+    Error => Error,
+    Success => Success,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum RangeSize {
@@ -35,17 +33,13 @@ pub enum RangeSize {
     Medium,
 }
 
-impl Display for RangeSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            RangeSize::Default => write!(f, "range-sm"),
-            RangeSize::ExtraSmall => write!(f, "range-xs"),
-            RangeSize::Small => write!(f, "range-sm"),
-            RangeSize::Large => write!(f, "range-lg"),
-            RangeSize::Medium => write!(f, "range-md"),
-        }
-    }
-}
+legacy_enum!(RangeSize => Size, "range" {
+    Default => Sm,
+    ExtraSmall => Xs,
+    Small => Sm,
+    Medium => Md,
+    Large => Lg,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct RangeProps {
@@ -58,18 +52,28 @@ pub struct RangeProps {
     label: Option<String>,
     label_class: Option<String>,
     help_text: Option<String>,
-    range_color: Option<RangeColor>,
+    range_color: Option<Color>,
     step: Option<i32>,
+    /// Signal the slider reads its position from and writes it back to.
+    bind: Option<Signal<i32>>,
+    oninput: Option<EventHandler<FormEvent>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = input)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Range(props: RangeProps) -> Element {
-    let range_color = props.range_color.unwrap_or_default();
+    let range_color = props.range_color.unwrap_or_default().class("range");
     let class = props.class.unwrap_or_default();
+    let value = props.bind.map(|bind| bind()).unwrap_or(props.value);
+    let mut attributes = props.attributes;
+    attributes.extend(value_listeners(props.bind, props.oninput, props.onchange));
+
     rsx!(
         match props.label {
             Some(l) => rsx! {
-                label { class: props.label_class, "{l}" }
+                label { class: classes!(props.label_class.unwrap_or_default()), "{l}" }
             },
             None => rsx! {},
         }
@@ -77,10 +81,11 @@ pub fn Range(props: RangeProps) -> Element {
             "type": "range",
             min: "{props.min}",
             max: "{props.max}",
-            value: "{props.value}",
+            value: "{value}",
             step: props.step,
-            class: "range {range_color} {class}",
+            class: classes!("range", range_color, class),
             name: props.name,
+            ..attributes,
             {props.children}
         }
         match props.help_text {
@@ -97,9 +102,9 @@ pub fn Range(props: RangeProps) -> Element {
 #[test]
 fn test_range() {
     let props = RangeProps {
-        children: rsx!( "Hello" ),
+        children: rsx!("Hello"),
         class: Some("test".to_string()),
-        range_color: Some(RangeColor::Info),
+        range_color: Some(Color::Info),
         min: 0,
         max: 100,
         value: 50,
@@ -108,6 +113,10 @@ fn test_range() {
         label: Some("test".to_string()),
         label_class: Some("test".to_string()),
         help_text: Some("test".to_string()),
+        bind: None,
+        oninput: None,
+        onchange: None,
+        attributes: vec![],
     };
 
     let expected = r#"<label class="test">test</label><input type="range" min="0" max="100" value="50" step=10 class="range range-info test" name="test">Hello</input><label><span class="label-text-alt">test</span></label>"#;
@@ -119,7 +128,7 @@ fn test_range() {
 #[test]
 fn test_range_default() {
     let props = RangeProps {
-        children: rsx!( "Hello" ),
+        children: rsx!("Hello"),
         class: None,
         range_color: None,
         min: 0,
@@ -130,10 +139,35 @@ fn test_range_default() {
         label: None,
         label_class: None,
         help_text: None,
+        bind: None,
+        oninput: None,
+        onchange: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<input type="range" min="0" max="100" value="50" class="range  " name="test">Hello</input>"#;
+    let expected = r#"<input type="range" min="0" max="100" value="50" class="range" name="test">Hello</input>"#;
     let result = dioxus_ssr::render_element(Range(props));
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_range_bind() {
+    fn app() -> Element {
+        let volume = Signal::new(70);
+        rsx!(Range {
+            name: "volume",
+            min: 0,
+            max: 100,
+            value: 20,
+            bind: volume
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected =
+        r#"<input type="range" min="0" max="100" value="70" class="range" name="volume"></input>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
@@ -0,0 +1,188 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::attributes::value_listeners;
+use crate::classes;
+use crate::style::{Color, Modifier, Size};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RatingMask {
+    #[default]
+    Star,
+    Star2,
+    Heart,
+}
+
+impl Display for RatingMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RatingMask::Star => write!(f, "mask-star"),
+            RatingMask::Star2 => write!(f, "mask-star-2"),
+            RatingMask::Heart => write!(f, "mask-heart"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct RatingProps {
+    name: String,
+    class: Option<String>,
+    /// Number of stars, defaults to 5
+    count: Option<u32>,
+    value: Option<f32>,
+    half: Option<bool>,
+    /// Adds a hidden radio with value 0 so the rating can be cleared
+    clearable: Option<bool>,
+    /// Show the value (e.g. an average) without any inputs
+    read_only: Option<bool>,
+    disabled: Option<bool>,
+    rating_mask: Option<RatingMask>,
+    rating_color: Option<Color>,
+    rating_size: Option<Size>,
+    /// Holds the rating, set when a star is picked.
+    bind: Option<Signal<f32>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Radios named `name`, so the chosen value is posted with the form.
+#[component]
+pub fn Rating(props: RatingProps) -> Element {
+    let rating_mask = props.rating_mask.unwrap_or_default();
+    let rating_color = props.rating_color.unwrap_or_default().class("bg");
+    let rating_size = props.rating_size.unwrap_or_default().class("rating");
+    let class = props.class.unwrap_or_default();
+    let disabled = props.disabled.filter(|&d| d);
+    let half = props.half.unwrap_or(false);
+
+    let steps_per_item = if half { 2 } else { 1 };
+    let steps = props.count.unwrap_or(5) * steps_per_item;
+    let value = props.bind.map(|bind| bind()).or(props.value);
+    let current = (value.unwrap_or(0.0) * steps_per_item as f32).round() as u32;
+    let half_class = if half { "rating-half" } else { "" };
+
+    let items: Vec<(String, String, bool)> = (1..=steps)
+        .map(|step| {
+            let value = (step as f32 / steps_per_item as f32).to_string();
+            let half_part = match (half, step % 2) {
+                (false, _) => "",
+                (true, 1) => "mask-half-1",
+                (true, _) => "mask-half-2",
+            };
+            (value, half_part.to_string(), step == current)
+        })
+        .collect();
+
+    if props.read_only.unwrap_or(false) {
+        rsx!(
+            div { class: classes!("rating", half_class, rating_size, class), ..props.attributes,
+                for (value , half_part , is_current) in items {
+                    div {
+                        class: classes!("mask", rating_mask, half_part, rating_color),
+                        "aria-label": "{value} star",
+                        "aria-current": is_current.then_some("true"),
+                    }
+                }
+            }
+        )
+    } else {
+        let mut attributes = props.attributes;
+        attributes.extend(value_listeners(props.bind, None, props.onchange));
+        rsx!(
+            div { class: classes!("rating", half_class, rating_size, class), ..attributes,
+                if props.clearable.unwrap_or(false) {
+                    input {
+                        "type": "radio",
+                        name: "{props.name}",
+                        value: "0",
+                        class: "rating-hidden",
+                        "aria-label": "clear",
+                        checked: (current == 0).then_some("checked"),
+                        disabled,
+                    }
+                }
+                for (value , half_part , is_current) in items {
+                    input {
+                        "type": "radio",
+                        name: "{props.name}",
+                        value: "{value}",
+                        class: classes!("mask", rating_mask, half_part, rating_color),
+                        "aria-label": "{value} star",
+                        checked: is_current.then_some("checked"),
+                        disabled,
+                    }
+                }
+            }
+        )
+    }
+}
+
+#[test]
+fn test_rating() {
+    let props = RatingProps {
+        name: "rating".to_string(),
+        class: None,
+        count: Some(3),
+        value: Some(2.0),
+        half: None,
+        clearable: Some(true),
+        read_only: None,
+        disabled: None,
+        rating_mask: Some(RatingMask::Heart),
+        rating_color: Some(Color::Error),
+        rating_size: Some(Size::Lg),
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<div class="rating rating-lg"><input type="radio" name="rating" value="0" class="rating-hidden" aria-label="clear"/><input type="radio" name="rating" value="1" class="mask mask-heart bg-error" aria-label="1 star"/><input type="radio" name="rating" value="2" class="mask mask-heart bg-error" aria-label="2 star" checked="checked"/><input type="radio" name="rating" value="3" class="mask mask-heart bg-error" aria-label="3 star"/></div>"#;
+    let result = dioxus_ssr::render_element(Rating(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_rating_half_read_only() {
+    let props = RatingProps {
+        name: "average".to_string(),
+        class: None,
+        count: Some(1),
+        value: Some(0.6),
+        half: Some(true),
+        clearable: None,
+        read_only: Some(true),
+        disabled: None,
+        rating_mask: None,
+        rating_color: None,
+        rating_size: None,
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<div class="rating rating-half rating-md"><div class="mask mask-star mask-half-1" aria-label="0.5 star" aria-current="true"></div><div class="mask mask-star mask-half-2" aria-label="1 star"></div></div>"#;
+    let result = dioxus_ssr::render_element(Rating(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_rating_bind() {
+    fn app() -> Element {
+        let stars = Signal::new(2.0);
+        rsx!(Rating {
+            name: "stars",
+            count: 3,
+            value: 1.0,
+            bind: stars
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<div class="rating rating-md"><input type="radio" name="stars" value="1" class="mask mask-star" aria-label="1 star"/><input type="radio" name="stars" value="2" class="mask mask-star" aria-label="2 star" checked="checked"/><input type="radio" name="stars" value="3" class="mask mask-star" aria-label="3 star"/></div>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
@@ -3,6 +3,10 @@ use std::fmt::Display;
 
 use dioxus::prelude::*;
 
+use crate::attributes::value_listeners;
+use crate::classes;
+use crate::style::{Modifier, Size};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum SelectSize {
     #[default]
@@ -13,22 +17,34 @@ pub enum SelectSize {
     Medium,
 }
 
+impl From<SelectSize> for Option<Size> {
+    fn from(value: SelectSize) -> Self {
+        match value {
+            // A select is medium without a modifier
+            SelectSize::Default => None,
+            SelectSize::Small => Some(Size::Sm),
+            SelectSize::ExtraSmall => Some(Size::Xs),
+            SelectSize::Large => Some(Size::Lg),
+            SelectSize::Medium => Some(Size::Md),
+        }
+    }
+}
+
 impl Display for SelectSize {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            SelectSize::Default => write!(f, ""),
-            SelectSize::Small => write!(f, "select-sm"),
-            SelectSize::ExtraSmall => write!(f, "select-xs"),
-            SelectSize::Large => write!(f, "select-lg"),
-            SelectSize::Medium => write!(f, "select-md"),
-        }
+        let size: Option<Size> = (*self).into();
+        write!(
+            f,
+            "{}",
+            size.map(|size| size.class("select")).unwrap_or_default()
+        )
     }
 }
 
 #[derive(Props, Clone, PartialEq)]
 pub struct SelectProps {
     children: Element,
-    select_size: Option<SelectSize>,
+    select_size: Option<Size>,
     pub name: String,
     pub id: Option<String>,
     pub value: Option<String>,
@@ -38,18 +54,32 @@ pub struct SelectProps {
     pub required: Option<bool>,
     pub disabled: Option<bool>,
     pub multiple: Option<bool>,
+    /// The selected value as a signal, for client-side apps.
+    pub bind: Option<Signal<String>>,
+    pub onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = select)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn Select(props: SelectProps) -> Element {
-    let select_size = props.select_size.unwrap_or_default();
-    let value = props.value.unwrap_or_default();
+    let select_size = props
+        .select_size
+        .map(|size| size.class("select"))
+        .unwrap_or_default();
+    let value = props
+        .bind
+        .map(|bind| bind())
+        .or(props.value)
+        .unwrap_or_default();
     let disabled = props.disabled.filter(|&d| d);
+    let mut attributes = props.attributes;
+    attributes.extend(value_listeners(props.bind, None, props.onchange));
 
     rsx!(
         match props.label {
             Some(l) => rsx! {
-                label { class: props.label_class, "{l}" }
+                label { class: classes!(props.label_class.unwrap_or_default()), "{l}" }
             },
             None => rsx! {},
         }
@@ -58,9 +88,10 @@ pub fn Select(props: SelectProps) -> Element {
             required: props.required,
             disabled,
             multiple: props.multiple,
-            class: "select select-bordered {select_size}",
+            class: classes!("select select-bordered", select_size),
             value: "{value}",
             name: "{props.name}",
+            ..attributes,
             {props.children}
         }
         match props.help_text {
@@ -79,6 +110,8 @@ pub struct OptionProps {
     children: Element,
     pub value: String,
     pub selected_value: Option<String>,
+    #[props(extends = GlobalAttributes, extends = option)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
@@ -86,7 +119,8 @@ pub fn SelectOption(props: OptionProps) -> Element {
     rsx!(
         option {
             value: props.value.clone(),
-            selected: props.selected_value.as_ref().map_or(false, |s| s == &props.value),
+            selected: props.selected_value.as_ref() == Some(&props.value),
+            ..props.attributes,
             {props.children}
         }
     )
@@ -97,7 +131,8 @@ fn test_select_option() {
     let props = OptionProps {
         value: "test".to_string(),
         selected_value: Some("test".to_string()),
-        children: rsx!( "Hello" ),
+        children: rsx!("Hello"),
+        attributes: vec![],
     };
 
     let expected = r#"<option value="test" selected=true>Hello</option>"#;
@@ -121,7 +156,7 @@ fn test_select() {
                 children: rsx! { "Hello2" },
             }
         },
-        select_size: Some(SelectSize::Large),
+        select_size: Some(Size::Lg),
         name: "test".to_string(),
         id: Some("test".to_string()),
         value: Some("test".to_string()),
@@ -131,6 +166,9 @@ fn test_select() {
         required: Some(true),
         disabled: Some(false),
         multiple: Some(false),
+        bind: None,
+        onchange: None,
+        attributes: vec![],
     };
 
     let expected = r#"<label class="test">test</label><select id="test" required=true class="select select-bordered select-lg" value="test" name="test"><option value="test" selected=true>Hello</option><option value="test2">Hello2</option></select><label class="label-text-alt"><span>test</span></label>"#;
@@ -138,3 +176,22 @@ fn test_select() {
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_select_bind() {
+    fn app() -> Element {
+        let country = Signal::new("fr".to_string());
+        rsx!(Select {
+            name: "country",
+            value: "de",
+            bind: country,
+            SelectOption { value: "fr", "France" }
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<select class="select select-bordered" value="fr" name="country"><option value="fr">France</option></select>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
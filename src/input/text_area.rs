@@ -1,8 +1,10 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
+use crate::attributes::value_listeners;
+use crate::classes;
+use crate::style::{legacy_enum, Modifier, Size};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TextAreaSize {
     #[default]
@@ -13,22 +15,18 @@ pub enum TextAreaSize {
     Medium,
 }
 
-impl Display for TextAreaSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            TextAreaSize::Default => write!(f, "textarea-sm"),
-            TextAreaSize::Small => write!(f, "textarea-sm"),
-            TextAreaSize::ExtraSmall => write!(f, "textarea-xs"),
-            TextAreaSize::Large => write!(f, "textarea-lg"),
-            TextAreaSize::Medium => write!(f, "textarea-md"),
-        }
-    }
-}
+legacy_enum!(TextAreaSize => Size, "textarea" {
+    Default => Sm,
+    ExtraSmall => Xs,
+    Small => Sm,
+    Medium => Md,
+    Large => Lg,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct Props {
     children: Element,
-    area_size: Option<TextAreaSize>,
+    area_size: Option<Size>,
     pub name: String,
     pub id: Option<String>,
     pub class: Option<String>,
@@ -41,28 +39,44 @@ pub struct Props {
     pub required: Option<bool>,
     pub disabled: Option<bool>,
     pub readonly: Option<bool>,
+    /// Keeps the text in a signal, for client-side apps.
+    pub bind: Option<Signal<String>>,
+    pub oninput: Option<EventHandler<FormEvent>>,
+    pub onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = textarea)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
 pub fn TextArea(props: Props) -> Element {
-    let input_size = props.area_size.unwrap_or_default();
-    let class = format!("{} {}", props.class.unwrap_or_default(), input_size);
-    let value = props.value.unwrap_or_default();
+    let input_size = props.area_size.unwrap_or(Size::Sm).class("textarea");
+    let class = classes!(
+        "textarea textarea-bordered",
+        input_size,
+        props.class.unwrap_or_default()
+    );
+    let value = props
+        .bind
+        .map(|bind| bind())
+        .or(props.value)
+        .unwrap_or_default();
     let placeholder = props.placeholder.unwrap_or_default();
     let label_class = props.label_class.unwrap_or_default();
 
     let disabled = props.disabled.unwrap_or(false);
+    let mut attributes = props.attributes;
+    attributes.extend(value_listeners(props.bind, props.oninput, props.onchange));
 
     rsx!(
         match props.label {
             Some(l) => rsx! {
-                label { class: "{label_class}", "{l}" }
+                label { class: classes!(label_class), "{l}" }
             },
             None => rsx! {},
         }
         textarea {
             id: props.id,
-            class: "textarea textarea-bordered textarea-sm {class}",
+            class,
             value: "{value}",
             name: "{props.name}",
             placeholder: "{placeholder}",
@@ -70,6 +84,7 @@ pub fn TextArea(props: Props) -> Element {
             disabled,
             readonly: props.readonly,
             rows: props.rows,
+            ..attributes,
             {props.children}
         }
         match props.help_text {
@@ -85,7 +100,7 @@ pub fn TextArea(props: Props) -> Element {
 fn test_text_area() {
     let props = Props {
         children: rsx! { "Hello" },
-        area_size: Some(TextAreaSize::Default),
+        area_size: TextAreaSize::Default.into(),
         name: "name".to_string(),
         id: Some("id".to_string()),
         class: Some("class".to_string()),
@@ -98,10 +113,32 @@ fn test_text_area() {
         required: Some(true),
         disabled: Some(false),
         readonly: Some(false),
+        bind: None,
+        oninput: None,
+        onchange: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<label class="label_class">label</label><textarea id="id" class="textarea textarea-bordered textarea-sm class textarea-sm" value="value" name="name" placeholder="placeholder" required=true rows="rows">Hello</textarea><span class="note mb-3">help_text</span>"#;
+    let expected = r#"<label class="label_class">label</label><textarea id="id" class="textarea textarea-bordered textarea-sm class" value="value" name="name" placeholder="placeholder" required=true rows="rows">Hello</textarea><span class="note mb-3">help_text</span>"#;
     let result = dioxus_ssr::render_element(TextArea(props));
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_text_area_bind() {
+    fn app() -> Element {
+        let notes = Signal::new("Draft".to_string());
+        rsx!(TextArea {
+            name: "notes",
+            value: "ignored",
+            bind: notes
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<textarea class="textarea textarea-bordered textarea-sm" value="Draft" name="notes" placeholder=""></textarea>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
@@ -0,0 +1,166 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::attributes::checked_listeners;
+use crate::classes;
+use crate::style::{Color, Modifier, Size};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ToggleProps {
+    id: Option<String>,
+    checked: Option<bool>,
+    class: Option<String>,
+    name: String,
+    value: String,
+    label: Option<String>,
+    description: Option<String>,
+    disabled: Option<bool>,
+    /// Can only be set from JS, so we flag it for our scripts to pick up.
+    indeterminate: Option<bool>,
+    toggle_size: Option<Size>,
+    toggle_color: Option<Color>,
+    /// Drives `checked`, and is updated when the toggle is flipped.
+    bind: Option<Signal<bool>>,
+    onchange: Option<EventHandler<FormEvent>>,
+    #[props(extends = GlobalAttributes, extends = input)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Toggle(props: ToggleProps) -> Element {
+    let toggle_color = props.toggle_color.unwrap_or_default().class("toggle");
+    let toggle_size = props.toggle_size.unwrap_or_default().class("toggle");
+    let class = props.class.unwrap_or_default();
+    let disabled = props.disabled.filter(|&d| d);
+
+    let checked = props
+        .bind
+        .map(|bind| bind())
+        .or(props.checked)
+        .and_then(|checked| checked.then_some("checked"));
+    let indeterminate = props
+        .indeterminate
+        .and_then(|indeterminate| indeterminate.then_some("true"));
+
+    let mut attributes = props.attributes;
+    attributes.extend(checked_listeners(props.bind, props.onchange));
+
+    let toggle = rsx!(
+        input {
+            "type": "checkbox",
+            class: classes!("toggle", toggle_color, toggle_size, class),
+            id: props.id,
+            name: props.name,
+            value: props.value,
+            checked,
+            disabled,
+            "data-indeterminate": indeterminate,
+            ..attributes,
+        }
+    );
+
+    match (props.label, props.description) {
+        (None, None) => toggle,
+        (label, description) => rsx!(
+            label { class: "label",
+                {toggle}
+                if let Some(label) = label {
+                    "{label}"
+                }
+            }
+            if let Some(description) = description {
+                p { class: "label", "{description}" }
+            }
+        ),
+    }
+}
+
+#[test]
+fn test_toggle() {
+    let props = ToggleProps {
+        name: "name".to_string(),
+        value: "value".to_string(),
+        checked: Some(true),
+        class: Some("custom".to_string()),
+        label: None,
+        description: None,
+        disabled: Some(true),
+        indeterminate: None,
+        toggle_size: Some(Size::Lg),
+        toggle_color: Some(Color::Success),
+        id: Some("id".to_string()),
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<input type="checkbox" class="toggle toggle-success toggle-lg custom" id="id" name="name" value="value" checked="checked" disabled=true/>"#;
+    let result = dioxus_ssr::render_element(Toggle(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_toggle_default() {
+    let props = ToggleProps {
+        name: "name".to_string(),
+        value: "value".to_string(),
+        checked: Some(false),
+        class: None,
+        label: None,
+        description: None,
+        disabled: None,
+        indeterminate: Some(true),
+        toggle_size: None,
+        toggle_color: None,
+        id: None,
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<input type="checkbox" class="toggle toggle-md" name="name" value="value" data-indeterminate="true"/>"#;
+    let result = dioxus_ssr::render_element(Toggle(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_toggle_with_label() {
+    let props = ToggleProps {
+        name: "notify".to_string(),
+        value: "on".to_string(),
+        checked: None,
+        class: None,
+        label: Some("Notifications".to_string()),
+        description: Some("Email me when a job finishes".to_string()),
+        disabled: None,
+        indeterminate: None,
+        toggle_size: None,
+        toggle_color: Some(Color::Primary),
+        id: None,
+        bind: None,
+        onchange: None,
+        attributes: vec![],
+    };
+    let expected = r#"<label class="label"><input type="checkbox" class="toggle toggle-primary toggle-md" name="notify" value="on"/>Notifications</label><p class="label">Email me when a job finishes</p>"#;
+    let result = dioxus_ssr::render_element(Toggle(props));
+    // println!("{}", result);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_toggle_bind() {
+    fn app() -> Element {
+        let enabled = Signal::new(true);
+        rsx!(Toggle {
+            name: "notifications",
+            value: "on",
+            bind: enabled
+        })
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r#"<input type="checkbox" class="toggle toggle-md" name="notifications" value="on" checked="checked"/>"#;
+    let result = dioxus_ssr::render(&dom);
+    assert_eq!(expected, result);
+}
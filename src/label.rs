@@ -1,8 +1,9 @@
 #![allow(non_snake_case)]
-use std::fmt::Display;
-
 use dioxus::prelude::*;
 
+use crate::classes;
+use crate::style::{legacy_enum, Color, Modifier, Size};
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LabelRole {
     #[default]
@@ -15,19 +16,15 @@ pub enum LabelRole {
     Highlight,
 }
 
-impl Display for LabelRole {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LabelRole::Neutral => write!(f, "badge-neutral"),
-            LabelRole::Danger => write!(f, "badge-danger"),
-            LabelRole::Warning => write!(f, "badge-warning"),
-            LabelRole::Success => write!(f, "badge-success"),
-            LabelRole::Info => write!(f, "badge-info"),
-            LabelRole::Highlight => write!(f, "badge-highlight"),
-            LabelRole::Default => write!(f, ""),
-        }
-    }
-}
+legacy_enum!(LabelRole => Color, "badge" {
+    Default => Default,
+    Neutral => Neutral,
+    Danger => Error,
+    Warning => Warning,
+    Success => Success,
+    Info => Info,
+    Highlight => Accent,
+});
 
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LabelSize {
@@ -36,45 +33,51 @@ pub enum LabelSize {
     Large,
 }
 
-impl Display for LabelSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LabelSize::Small => write!(f, ""),
-            LabelSize::Large => write!(f, "badge-lg"),
-        }
-    }
-}
+legacy_enum!(LabelSize => Size, "badge" {
+    Small => Sm,
+    Large => Lg,
+});
 
 #[derive(Props, Clone, PartialEq)]
 pub struct LabelProps {
     children: Element,
     class: Option<String>,
-    label_role: Option<LabelRole>,
-    label_size: Option<LabelSize>,
+    label_role: Option<Color>,
+    label_size: Option<Size>,
 }
 
 #[component]
 pub fn Label(props: LabelProps) -> Element {
-    let label_role = props.label_role.unwrap_or_default();
-    let label_size = props.label_size.unwrap_or_default();
+    let label_role = props.label_role.unwrap_or_default().class("badge");
+    // A badge is medium without a modifier
+    let label_size = props
+        .label_size
+        .map(|size| size.class("badge"))
+        .unwrap_or_default();
     let class = props.class.unwrap_or_default();
 
     rsx!(
-        button { class: "badge {label_role} {label_size} {class}", {props.children} }
+        button { class: classes!("badge", label_role, label_size, class), {props.children} }
     )
 }
 
 #[test]
 fn test_label() {
     let props = LabelProps {
-        children: rsx!( "Hello" ),
+        children: rsx!("Hello"),
         class: Some("test".to_string()),
-        label_role: Some(LabelRole::Danger),
-        label_size: Some(LabelSize::Large),
+        label_role: LabelRole::Danger.into(),
+        label_size: LabelSize::Large.into(),
     };
 
-    let expected = r#"<button class="badge badge-danger badge-lg test">Hello</button>"#;
+    let expected = r#"<button class="badge badge-error badge-lg test">Hello</button>"#;
     let result = dioxus_ssr::render_element(Label(props));
     // println!("{}", result);
     assert_eq!(result, expected);
 }
+
+#[test]
+fn test_label_default() {
+    let result = dioxus_ssr::render_element(rsx!(Label { "Hello" }));
+    assert_eq!(result, r#"<button class="badge">Hello</button>"#);
+}
@@ -0,0 +1,115 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::classes;
+use crate::style::{Color, Modifier};
+
+/// DaisyUI names the direction by how the divided content is stacked, so a
+/// `Vertical` divider draws a horizontal line between stacked blocks.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DividerDirection {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+impl Display for DividerDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DividerDirection::Vertical => write!(f, "divider-vertical"),
+            DividerDirection::Horizontal => write!(f, "divider-horizontal"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DividerPlacement {
+    #[default]
+    Center,
+    Start,
+    End,
+}
+
+impl Display for DividerPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DividerPlacement::Center => write!(f, ""),
+            DividerPlacement::Start => write!(f, "divider-start"),
+            DividerPlacement::End => write!(f, "divider-end"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DividerProps {
+    text: Option<String>,
+    class: Option<String>,
+    direction: Option<DividerDirection>,
+    divider_color: Option<Color>,
+    placement: Option<DividerPlacement>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A plain `div`, so it can sit between the children of a `CardBody`, a
+/// `Fieldset` or a form. Inside a `Menu` use `MenuDivider`.
+#[component]
+pub fn Divider(props: DividerProps) -> Element {
+    let direction = props.direction.unwrap_or_default();
+    let divider_color = props.divider_color.unwrap_or_default().class("divider");
+    let placement = props.placement.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+    let orientation = match direction {
+        DividerDirection::Vertical => "horizontal",
+        DividerDirection::Horizontal => "vertical",
+    };
+
+    rsx!(
+        div {
+            role: "separator",
+            "aria-orientation": orientation,
+            class: classes!("divider", direction, divider_color, placement, class),
+            ..props.attributes,
+            if let Some(text) = props.text {
+                "{text}"
+            }
+        }
+    )
+}
+
+#[test]
+fn test_divider() {
+    let props = DividerProps {
+        text: Some("OR".to_string()),
+        class: None,
+        direction: None,
+        divider_color: Some(Color::Primary),
+        placement: Some(DividerPlacement::Start),
+        attributes: vec![],
+    };
+
+    let expected = r#"<div role="separator" aria-orientation="horizontal" class="divider divider-vertical divider-primary divider-start">OR</div>"#;
+    let result = dioxus_ssr::render_element(Divider(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_divider_in_card() {
+    use crate::{Card, CardBody};
+
+    let card = rsx!(
+        Card {
+            CardBody {
+                "Top"
+                Divider { direction: DividerDirection::Horizontal, id: "split" }
+                "Bottom"
+            }
+        }
+    );
+
+    let expected = r#"<div class="card"><div class="card-body">Top<div role="separator" aria-orientation="vertical" class="divider divider-horizontal" id="split"></div>Bottom</div></div>"#;
+    let result = dioxus_ssr::render_element(card);
+    assert_eq!(result, expected);
+}
@@ -2,81 +2,170 @@
 
 use dioxus::prelude::*;
 
+use crate::attributes::use_return_focus;
+use crate::classes;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct DrawerProps {
     trigger_id: String,
     label: String,
     children: Element,
     submit_action: Option<String>,
+    /// Shows the drawer from a client-side app instead of the `trigger_id`
+    /// anchor. Esc, the overlay and the close link set it to `false`.
+    open: Option<Signal<bool>>,
+    /// Called after the drawer closes itself.
+    on_close: Option<EventHandler<()>>,
 }
 
 #[component]
 pub fn Drawer(props: DrawerProps) -> Element {
+    if props.open.is_some() {
+        return rsx!(SignalDrawer { ..props });
+    }
+
+    DrawerPanel(props, None)
+}
+
+/// Only rendered while `open` is set, and closed by setting it to `false`.
+#[component]
+fn SignalDrawer(props: DrawerProps) -> Element {
+    let Some(mut open) = props.open else {
+        return rsx!();
+    };
+    if !open() {
+        return rsx!();
+    }
+
+    let on_close = props.on_close;
+    let close = EventHandler::new(move |_| {
+        open.set(false);
+        if let Some(on_close) = on_close {
+            on_close.call(());
+        }
+    });
+
+    DrawerPanel(props, Some(close))
+}
+
+/// The drawer markup. With `close` the drawer gets `side-drawer--open`, so it
+/// shows without the anchor being targeted, and Esc, the overlay and the
+/// close link call it.
+fn DrawerPanel(props: DrawerProps, close: Option<EventHandler>) -> Element {
+    let is_open = close.is_some();
+    let mut dialog = vec![];
+    let mut overlay = vec![];
+    let mut close_link = vec![];
+    if let Some(close) = close {
+        dialog.push(dioxus_elements::events::onmounted(
+            move |event: MountedEvent| async move {
+                _ = event.set_focus(true).await;
+            },
+        ));
+        dialog.push(dioxus_elements::events::onkeydown(
+            move |event: KeyboardEvent| {
+                if event.key() == Key::Escape {
+                    close.call(());
+                }
+            },
+        ));
+        overlay.push(dioxus_elements::events::onclick(move |_| close.call(())));
+        close_link.push(dioxus_elements::events::onclick(
+            move |event: MouseEvent| {
+                event.prevent_default();
+                close.call(());
+            },
+        ));
+    }
+
+    let drawer = rsx!(
+        div {
+            class: if is_open { "side-drawer side-drawer--open flex flex-col" } else { "side-drawer flex flex-col" },
+            id: props.trigger_id,
+            role: is_open.then_some("dialog"),
+            "aria-modal": is_open.then_some("true"),
+            tabindex: is_open.then_some("-1"),
+            ..dialog,
+            div {
+                class: "drawer__overlay",
+                tabindex: "-1",
+                ..overlay
+            }
+            div {
+                class: "drawer__panel",
+                header {
+                    class: "drawer__header",
+                    h4 {
+                        class: "drawer__title",
+                        "{props.label}"
+                    }
+                    a {
+                        href: "#",
+                        class: "drawer__close",
+                        ..close_link,
+                        "X"
+                    }
+                }
+                {props.children}
+            }
+        }
+    );
+
     if let Some(action) = &props.submit_action {
         rsx!(
             form {
                 action: "{action}",
                 method: "post",
                 div {
-                    div {
-                        class: "side-drawer flex flex-col",
-                        id: props.trigger_id,
-                        div {
-                            class: "drawer__overlay",
-                            tabindex: "-1"
-                        }
-                        div {
-                            class: "drawer__panel",
-                            header {
-                                class: "drawer__header",
-                                h4 {
-                                    class: "drawer__title",
-                                    "{props.label}"
-                                }
-                                a {
-                                    href: "#",
-                                    class: "drawer__close",
-                                    "X"
-                                }
-                            }
-                            {props.children}
-                        }
-                    }
+                    {drawer}
                 }
             }
         )
     } else {
         rsx!(
             div {
-                div {
-                    class: "side-drawer flex flex-col",
-                    id: props.trigger_id,
-                    div {
-                        class: "drawer__overlay",
-                        tabindex: "-1"
-                    }
-                    div {
-                        class: "drawer__panel",
-                        header {
-                            class: "drawer__header",
-                            h4 {
-                                class: "drawer__title",
-                                "{props.label}"
-                            }
-                            a {
-                                href: "#",
-                                class: "drawer__close",
-                                "X"
-                            }
-                        }
-                        {props.children}
-                    }
-                }
+                {drawer}
             }
         )
     }
 }
 
+#[derive(Props, Clone, PartialEq)]
+pub struct DrawerTriggerProps {
+    open: Signal<bool>,
+    class: Option<String>,
+    children: Element,
+    onclick: Option<EventHandler<MouseEvent>>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Opens a `Drawer` with the same `open` signal, and gets focus back when it
+/// closes.
+#[component]
+pub fn DrawerTrigger(props: DrawerTriggerProps) -> Element {
+    let mut open = props.open;
+    let onmounted = use_return_focus(open);
+    let onclick = props.onclick;
+
+    rsx!(
+        button {
+            class: classes!("btn", props.class.unwrap_or_default()),
+            "type": "button",
+            "aria-haspopup": "dialog",
+            onclick: move |event| {
+                open.set(true);
+                if let Some(onclick) = onclick {
+                    onclick.call(event);
+                }
+            },
+            onmounted,
+            ..props.attributes,
+            {props.children}
+        }
+    )
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct DrawerFooterProps {
     children: Element,
@@ -100,17 +189,83 @@ pub struct DrawerBodyProps {
 
 #[component]
 pub fn DrawerBody(props: DrawerBodyProps) -> Element {
-    let class = if let Some(class) = props.class {
-        class
-    } else {
-        "".to_string()
-    };
-
-    let class = format!("drawer__body {}", class);
+    let class = classes!("drawer__body", props.class.unwrap_or_default());
     rsx!(
         div {
-            class: "{class}",
+            class,
             {props.children}
         }
     )
 }
+
+#[test]
+fn test_drawer_open_signal() {
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        rsx!(
+            DrawerTrigger { open, id: "edit-trigger", "Edit" }
+            Drawer { trigger_id: "edit", label: "Edit", open, "Body" }
+        )
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let expected = r##"<button class="btn" type="button" aria-haspopup="dialog" id="edit-trigger">Edit</button><div><div class="side-drawer side-drawer--open flex flex-col" id="edit" role="dialog" aria-modal="true" tabindex="-1"><div class="drawer__overlay" tabindex="-1"></div><div class="drawer__panel"><header class="drawer__header"><h4 class="drawer__title">Edit</h4><a href="#" class="drawer__close">X</a></header>Body</div></div></div>"##;
+    assert_eq!(expected, dioxus_ssr::render(&dom));
+}
+
+#[test]
+fn test_drawer_close() {
+    use dioxus::dioxus_core::{Mutation, Mutations};
+    use dioxus_html::{PlatformEventData, SerializedHtmlEventConverter, SerializedMouseData};
+    use std::any::Any;
+    use std::rc::Rc;
+
+    fn app() -> Element {
+        let open = use_signal(|| true);
+        let mut closed = use_signal(|| false);
+        rsx!(
+            Drawer { trigger_id: "edit", label: "Edit", open, on_close: move |_| closed.set(true), "Body" }
+            if closed() {
+                "Closed"
+            }
+        )
+    }
+
+    dioxus_html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+    let mut dom = VirtualDom::new(app);
+    let mut mutations = Mutations::default();
+    dom.rebuild(&mut mutations);
+    assert!(dioxus_ssr::render(&dom).contains("side-drawer--open"));
+    let overlay = mutations
+        .edits
+        .iter()
+        .find_map(|edit| match edit {
+            Mutation::NewEventListener { name, id } if name == "click" => Some(*id),
+            _ => None,
+        })
+        .unwrap();
+
+    let click = PlatformEventData::new(Box::new(SerializedMouseData::default()));
+    let click: Rc<dyn Any> = Rc::new(click);
+    dom.runtime()
+        .handle_event("click", Event::new(click, true), overlay);
+    dom.render_immediate_to_vec();
+    assert_eq!("Closed", dioxus_ssr::render(&dom));
+}
+
+#[test]
+fn test_drawer() {
+    let props = DrawerProps {
+        trigger_id: "edit".to_string(),
+        label: "Edit".to_string(),
+        children: rsx!("Body"),
+        submit_action: None,
+        open: None,
+        on_close: None,
+    };
+
+    let expected = r##"<div><div class="side-drawer flex flex-col" id="edit"><div class="drawer__overlay" tabindex="-1"></div><div class="drawer__panel"><header class="drawer__header"><h4 class="drawer__title">Edit</h4><a href="#" class="drawer__close">X</a></header>Body</div></div></div>"##;
+    let result = dioxus_ssr::render_element(Drawer(props));
+    assert_eq!(expected, result);
+}
@@ -0,0 +1,152 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::classes;
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FooterLink {
+    pub label: String,
+    pub href: String,
+}
+
+/// A titled column of links.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FooterColumn {
+    pub title: String,
+    pub links: Vec<FooterLink>,
+}
+
+/// `icon` is an image src, `label` is used as the accessible name.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SocialLink {
+    pub label: String,
+    pub href: String,
+    pub icon: String,
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FooterDirection {
+    /// Stacked on small screens, side by side from `sm` up
+    #[default]
+    Responsive,
+    Horizontal,
+    Vertical,
+}
+
+impl Display for FooterDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FooterDirection::Responsive => write!(f, "footer-vertical sm:footer-horizontal"),
+            FooterDirection::Horizontal => write!(f, "footer-horizontal"),
+            FooterDirection::Vertical => write!(f, "footer-vertical"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FooterProps {
+    brand: Option<Element>,
+    #[props(default)]
+    columns: Vec<FooterColumn>,
+    #[props(default)]
+    social: Vec<SocialLink>,
+    copyright: Option<String>,
+    direction: Option<FooterDirection>,
+    centered: Option<bool>,
+    class: Option<String>,
+}
+
+#[component]
+pub fn Footer(props: FooterProps) -> Element {
+    let direction = props.direction.unwrap_or_default();
+    let centered = if props.centered.unwrap_or(false) {
+        "footer-center"
+    } else {
+        ""
+    };
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        footer { class: classes!("footer", direction, centered, "bg-base-200 text-base-content p-10", class),
+            if let Some(brand) = props.brand {
+                aside { {brand} }
+            }
+            for column in props.columns {
+                nav {
+                    h6 { class: "footer-title", "{column.title}" }
+                    for link in column.links {
+                        a { class: "link link-hover", href: "{link.href}", "{link.label}" }
+                    }
+                }
+            }
+            if !props.social.is_empty() {
+                nav {
+                    div { class: "grid grid-flow-col gap-4",
+                        for link in props.social {
+                            a {
+                                href: "{link.href}",
+                                target: "_blank",
+                                rel: "noopener noreferrer",
+                                "aria-label": "{link.label}",
+                                img { class: "w-6 h-6", src: "{link.icon}", alt: "" }
+                            }
+                        }
+                    }
+                }
+            }
+            if let Some(copyright) = props.copyright {
+                aside {
+                    p { "{copyright}" }
+                }
+            }
+        }
+    )
+}
+
+#[test]
+fn test_footer() {
+    let props = FooterProps {
+        brand: Some(rsx!(
+            p { "ACME Industries" }
+        )),
+        columns: vec![FooterColumn {
+            title: "Company".to_string(),
+            links: vec![FooterLink {
+                label: "About".to_string(),
+                href: "/about".to_string(),
+            }],
+        }],
+        social: vec![SocialLink {
+            label: "GitHub".to_string(),
+            href: "https://github.com/acme".to_string(),
+            icon: "/github.svg".to_string(),
+        }],
+        copyright: Some("© 2024 ACME".to_string()),
+        direction: Some(FooterDirection::Horizontal),
+        centered: Some(true),
+        class: None,
+    };
+
+    let expected = r#"<footer class="footer footer-horizontal footer-center bg-base-200 text-base-content p-10"><aside><p>ACME Industries</p></aside><nav><h6 class="footer-title">Company</h6><a class="link link-hover" href="/about">About</a></nav><nav><div class="grid grid-flow-col gap-4"><a href="https://github.com/acme" target="_blank" rel="noopener noreferrer" aria-label="GitHub"><img class="w-6 h-6" src="/github.svg" alt=""/></a></div></nav><aside><p>© 2024 ACME</p></aside></footer>"#;
+    let result = dioxus_ssr::render_element(Footer(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_footer_copyright_only() {
+    let props = FooterProps {
+        brand: None,
+        columns: vec![],
+        social: vec![],
+        copyright: Some("© 2024 ACME".to_string()),
+        direction: None,
+        centered: None,
+        class: None,
+    };
+
+    let expected = r#"<footer class="footer footer-vertical sm:footer-horizontal bg-base-200 text-base-content p-10"><aside><p>© 2024 ACME</p></aside></footer>"#;
+    let result = dioxus_ssr::render_element(Footer(props));
+    assert_eq!(result, expected);
+}
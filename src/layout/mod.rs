@@ -1 +1,3 @@
+pub mod divider;
 pub mod drawer;
+pub mod footer;
@@ -1,7 +1,9 @@
 pub mod actions;
 pub mod app_layout;
+mod attributes;
 pub mod blank_slate;
 pub mod block;
+pub mod classes;
 pub mod feedback;
 pub mod input;
 pub mod label;
@@ -10,31 +12,65 @@ pub mod marketing;
 pub mod nav_item;
 pub mod navigation;
 pub mod relative_time;
+pub mod style;
 pub mod tab_container;
 pub mod time_line;
 pub mod timeline;
 
 pub use actions::button::{BtnColor, BtnShape, BtnSize, BtnStyle, BtnType, Button};
-pub use actions::drop_down::{Direction, DropDown, DropDownLink};
-pub use actions::modal::{Modal, ModalAction, ModalBody};
+pub use actions::drop_down::{Direction, DropDown, DropDownDivider, DropDownLink};
+pub use actions::modal::{DialogType, Modal, ModalAction, ModalBody, ModalTrigger};
+pub use actions::swap::{Swap, SwapAnimation, SwapType};
 pub use app_layout::AppLayout;
 pub use blank_slate::BlankSlate;
 pub use block::accordian::Accordian;
 pub use block::avatar::{Avatar, AvatarSize, AvatarType};
 pub use block::badge::{Badge, BadgeColor, BadgeSize, BadgeStyle};
 pub use block::card::{Card, CardBody, CardHeader};
+pub use block::carousel::{
+    carousel_neighbours, Carousel, CarouselDirection, CarouselIndicators, CarouselItem,
+    CarouselSnap,
+};
+pub use classes::Classes;
 pub use feedback::alert::{Alert, AlertColor};
+pub use feedback::skeleton::{
+    Skeleton, SkeletonAvatar, SkeletonCard, SkeletonTableRow, SkeletonText,
+};
+pub use feedback::toast::{Flash, FlashLevel, FlashToast, Toast, ToastMessage, ToastPosition};
+pub use feedback::tooltip::{ToolTip, ToolTipColor};
+pub use input::calendar::{Calendar, CalendarDate, DateInput, Weekday};
 pub use input::check_box::{CheckBox, CheckBoxScheme, CheckBoxSize};
 pub use input::fieldset::Fieldset;
 pub use input::file_input::{FileInput, FileInputColor, FileInputSize, FileInputStyle};
+pub use input::filter::Filter;
+pub use input::radio::{Radio, RadioGroup};
+pub use input::range::{Range, RangeColor, RangeSize};
+pub use input::rating::{Rating, RatingMask};
+pub use input::select::{Select, SelectOption, SelectSize};
 pub use input::text_area::{TextArea, TextAreaSize};
+pub use input::toggle::Toggle;
 pub use input::{Input, InputSize, InputType};
 pub use label::{Label, LabelRole, LabelSize};
-pub use layout::drawer::{Drawer, DrawerBody, DrawerFooter};
+pub use layout::divider::{Divider, DividerDirection, DividerPlacement};
+pub use layout::drawer::{Drawer, DrawerBody, DrawerFooter, DrawerTrigger};
+pub use layout::footer::{Footer, FooterColumn, FooterDirection, FooterLink, SocialLink};
+#[allow(deprecated)]
 pub use nav_item::{NavGroup, NavItem, NavSubGroup, NavSubItem};
 pub use navigation::breadcrumb::{Breadcrumb, BreadcrumbItem};
+pub use navigation::dock::{Dock, DockItem};
+pub use navigation::link::Link;
+pub use navigation::menu::{
+    Menu, MenuDirection, MenuDivider, MenuItem, MenuNode, MenuTitle, MenuTree, SubMenu,
+};
+pub use navigation::nav_tree::{
+    NavTree, NavTreeBreadcrumb, NavTreeDock, NavTreeGroup, NavTreeItem, NavTreeSidebar,
+};
+pub use navigation::navbar::Navbar;
 pub use navigation::pagination::Pagination;
+pub use navigation::steps::{Step, StepState, Steps, StepsDirection, WizardSteps};
+pub use navigation::tab::{LinkTab, LinkTabs, Tabs, TabsPlacement, TabsStyle};
 pub use relative_time::{RelativeTime, RelativeTimeFormat};
+pub use style::{Color, Modifier, Size, Variant};
 pub use tab_container::{TabContainer, TabPanel};
 pub use time_line::{TimeLine, TimeLineBadge, TimeLineBody};
 pub use timeline::{Timeline, TimelineEnd, TimelineItem, TimelineMiddle, TimelineStart};
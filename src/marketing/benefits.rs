@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[component]
 pub fn Benefits(
     title: String,
@@ -14,7 +16,7 @@ pub fn Benefits(
 ) -> Element {
     rsx! {
         section {
-            class: format!("lg:max-w-5xl {}", class.unwrap_or("".to_string())),
+            class: classes!("lg:max-w-5xl", class.unwrap_or_default()),
             div {
                 class: "container mx-auto",
                 div {
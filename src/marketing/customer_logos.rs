@@ -1,10 +1,12 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[component]
 pub fn Customers(class: Option<String>) -> Element {
     rsx! {
         section {
-            class: format!("flex flex-col items-center {}", class.unwrap_or("".to_string())),
+            class: classes!("flex flex-col items-center", class.unwrap_or_default()),
             span {
                 class: "badge badge-primary badge-outline",
                 "Trusted by the World's Best Companies"
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Clone, PartialEq)]
 pub struct FaqText {
     pub question: String,
@@ -11,7 +13,7 @@ pub fn Faq(questions: Vec<FaqText>, class: Option<String>) -> Element {
     let class = class.unwrap_or("".to_string());
     rsx! {
         section {
-            class: format!("{class} lg:max-w-5xl"),
+            class: classes!("lg:max-w-5xl", class),
             h1 {
                 class: "text-3xl font-medium text-primary title-font mb-12 text-center",
                 "Frequently asked questions"
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[component]
 pub fn GraphSvg() -> Element {
     rsx! {
@@ -37,7 +39,7 @@ pub fn Features(
     let class = class.unwrap_or("".to_string());
     rsx! {
         section {
-            class: format!("{class} body-font"),
+            class: classes!("body-font", class),
             div {
                 class: "mx-auto",
                 div {
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[component]
 pub fn ProblemSolution(
     image: String,
@@ -10,7 +12,7 @@ pub fn ProblemSolution(
 ) -> Element {
     rsx! {
         section {
-            class: format!("md:flex lg:max-w-5xl gap-8 w-full {}", class.unwrap_or("".to_string())),
+            class: classes!("md:flex lg:max-w-5xl gap-8 w-full", class.unwrap_or_default()),
             div {
                 class: "flex-1",
                 h1 {
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[component]
 pub fn Shield(text: String) -> Element {
     rsx! {
@@ -45,7 +47,7 @@ pub fn Security(class: Option<String>) -> Element {
     let class = class.unwrap_or("".to_string());
     rsx! {
         section {
-            class: format!("{class} md:flex flex-row gap-8"),
+            class: classes!("md:flex flex-row gap-8", class),
             div {
                 class: "flex-1",
                 h2 {
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[component]
 pub fn SmallImageFeature(
     title: String,
@@ -13,7 +15,7 @@ pub fn SmallImageFeature(
     let class = class.unwrap_or("".to_string());
     rsx! {
         section {
-            class: "{class} lg:max-w-5xl md:flex {flip} gap-8",
+            class: classes!("lg:max-w-5xl md:flex", flip, "gap-8", class),
             div {
                 class: "flex-1",
                 h2 {
@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[component]
 pub fn Testamonial(text: String, job: String, person: String, img: String) -> Element {
     rsx! {
@@ -56,7 +58,7 @@ pub fn Testamonials(
     let class = class.unwrap_or("".to_string());
     rsx! {
         section {
-            class: format!("mx-auto lg:max-w-5xl {class}"),
+            class: classes!("mx-auto lg:max-w-5xl", class),
             div {
                 class: "container mx-auto",
                 h1 {
@@ -1,6 +1,13 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 
+use crate::classes;
+
+/// The `id` / `selected_item_id` convention shared by our navigation components.
+pub(crate) fn is_selected(id: &Option<String>, selected_item_id: &Option<String>) -> bool {
+    matches!((id, selected_item_id), (Some(id), Some(selected_id)) if id == selected_id)
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct NavItemProps {
     href: String,
@@ -12,14 +19,15 @@ pub struct NavItemProps {
 
 #[component]
 pub fn NavItem(props: NavItemProps) -> Element {
-    let class = match (&props.id, &props.selected_item_id) {
-        (Some(id), Some(selected_id)) if id == selected_id => "active",
-        _ => "",
+    let class = if is_selected(&props.id, &props.selected_item_id) {
+        "active"
+    } else {
+        ""
     };
     rsx!(
         li { role: "listitem",
             a {
-                class: "{class}",
+                class: classes!(class),
                 href: "{props.href}",
                 "data-turbo-frame": "main-content",
                 img { width: "16", height: "16", src: "{props.icon}" }
@@ -39,13 +47,14 @@ pub struct NavSubItemProps {
 
 #[component]
 pub fn NavSubItem(props: NavSubItemProps) -> Element {
-    let class = match (&props.id, &props.selected_item_id) {
-        (Some(id), Some(selected_id)) if id == selected_id => "active",
-        _ => "",
+    let class = if is_selected(&props.id, &props.selected_item_id) {
+        "active"
+    } else {
+        ""
     };
 
     rsx!(
-        li { class,
+        li { class: classes!(class),
             a { href: "{props.href}", "{props.title}" }
         }
     )
@@ -57,6 +66,7 @@ pub struct NavGroupProps {
     content: Element,
 }
 
+#[deprecated(note = "use `Menu` with a `MenuTitle` instead")]
 #[component]
 pub fn NavGroup(props: NavGroupProps) -> Element {
     rsx!(
@@ -72,10 +82,11 @@ pub struct NavSubGroupProps {
     children: Element,
 }
 
+#[deprecated(note = "use a `SubMenu` inside a `Menu` instead")]
 #[component]
 pub fn NavSubGroup(props: NavSubGroupProps) -> Element {
     rsx!(
-        ul { role: "list", class: "ActionList ActionList--subGroup", {props.children} }
+        ul { role: "list", {props.children} }
     )
 }
 
@@ -94,3 +105,18 @@ fn test_nav_item() {
     // println!("{}", result);
     assert_eq!(expected, result);
 }
+
+#[test]
+fn test_nav_item_not_selected() {
+    let props = NavItemProps {
+        href: "test".to_string(),
+        icon: "test".to_string(),
+        title: "test".to_string(),
+        selected_item_id: Some("other".to_string()),
+        id: Some("test".to_string()),
+    };
+
+    let expected = r#"<li role="listitem"><a href="test" data-turbo-frame="main-content"><img width="16" height="16" src="test"/>test</a></li>"#;
+    let result = dioxus_ssr::render_element(NavItem(props));
+    assert_eq!(expected, result);
+}
@@ -1,6 +1,8 @@
 #![allow(non_snake_case)]
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct BreadcrumbItem {
     pub text: String,
@@ -11,6 +13,8 @@ pub struct BreadcrumbItem {
 pub struct BreadcrumbProps {
     items: Vec<BreadcrumbItem>,
     class: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
@@ -19,7 +23,8 @@ pub fn Breadcrumb(props: BreadcrumbProps) -> Element {
 
     rsx!(
         div {
-            class: "breadcrumbs text-sm {class}",
+            class: classes!("breadcrumbs text-sm", class),
+            ..props.attributes,
             ul {
                 for item in props.items {
                     li {
@@ -55,9 +60,10 @@ fn test_breadcrumb_basic() {
     let props = BreadcrumbProps {
         items,
         class: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<div class="breadcrumbs text-sm "><ul><li><a href="/">Home</a></li><li><a href="/documents">Documents</a></li><li>Add Document</li></ul></div>"#;
+    let expected = r#"<div class="breadcrumbs text-sm"><ul><li><a href="/">Home</a></li><li><a href="/documents">Documents</a></li><li>Add Document</li></ul></div>"#;
     let result = dioxus_ssr::render_element(Breadcrumb(props));
     assert_eq!(result, expected);
 }
@@ -78,6 +84,7 @@ fn test_breadcrumb_with_custom_class() {
     let props = BreadcrumbProps {
         items,
         class: Some("my-custom-class".to_string()),
+        attributes: vec![],
     };
 
     let expected = r#"<div class="breadcrumbs text-sm my-custom-class"><ul><li><a href="/">Home</a></li><li>Current</li></ul></div>"#;
@@ -90,9 +97,10 @@ fn test_breadcrumb_empty() {
     let props = BreadcrumbProps {
         items: vec![],
         class: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<div class="breadcrumbs text-sm "><ul></ul></div>"#;
+    let expected = r#"<div class="breadcrumbs text-sm"><ul></ul></div>"#;
     let result = dioxus_ssr::render_element(Breadcrumb(props));
     assert_eq!(result, expected);
 }
@@ -113,9 +121,10 @@ fn test_breadcrumb_only_links() {
     let props = BreadcrumbProps {
         items,
         class: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<div class="breadcrumbs text-sm "><ul><li><a href="/">Home</a></li><li><a href="/about">About</a></li></ul></div>"#;
+    let expected = r#"<div class="breadcrumbs text-sm"><ul><li><a href="/">Home</a></li><li><a href="/about">About</a></li></ul></div>"#;
     let result = dioxus_ssr::render_element(Breadcrumb(props));
     assert_eq!(result, expected);
 }
@@ -136,9 +145,11 @@ fn test_breadcrumb_only_text() {
     let props = BreadcrumbProps {
         items,
         class: None,
+        attributes: vec![],
     };
 
-    let expected = r#"<div class="breadcrumbs text-sm "><ul><li>Step 1</li><li>Step 2</li></ul></div>"#;
+    let expected =
+        r#"<div class="breadcrumbs text-sm"><ul><li>Step 1</li><li>Step 2</li></ul></div>"#;
     let result = dioxus_ssr::render_element(Breadcrumb(props));
     assert_eq!(result, expected);
-}
\ No newline at end of file
+}
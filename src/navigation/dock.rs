@@ -0,0 +1,84 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::classes;
+use crate::nav_item::is_selected;
+use crate::style::{Modifier, Size};
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DockProps {
+    children: Element,
+    class: Option<String>,
+    dock_size: Option<Size>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Bottom navigation for small screens, holding `DockItem`s.
+#[component]
+pub fn Dock(props: DockProps) -> Element {
+    let dock_size = props.dock_size.unwrap_or_default().class("dock");
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        nav { class: classes!("dock", dock_size, class), ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DockItemProps {
+    href: String,
+    icon: String,
+    title: String,
+    selected_item_id: Option<String>,
+    id: Option<String>,
+    #[props(extends = GlobalAttributes, extends = a)]
+    attributes: Vec<Attribute>,
+}
+
+/// Takes the same `id` and `selected_item_id` as `NavItem`, so both can be
+/// driven from the same data.
+#[component]
+pub fn DockItem(props: DockItemProps) -> Element {
+    let active = if is_selected(&props.id, &props.selected_item_id) {
+        "dock-active"
+    } else {
+        ""
+    };
+
+    rsx!(
+        a { class: classes!(active), href: "{props.href}", ..props.attributes,
+            img { width: "16", height: "16", src: "{props.icon}" }
+            span { class: "dock-label", "{props.title}" }
+        }
+    )
+}
+
+#[test]
+fn test_dock() {
+    let props = DockProps {
+        children: rsx!(
+            DockItem {
+                href: "/",
+                icon: "home.svg",
+                title: "Home",
+                id: "home",
+                selected_item_id: "home"
+            }
+            DockItem {
+                href: "/settings",
+                icon: "settings.svg",
+                title: "Settings",
+                id: "settings",
+                selected_item_id: "home"
+            }
+        ),
+        class: None,
+        dock_size: Some(Size::Sm),
+        attributes: vec![],
+    };
+
+    let expected = r#"<nav class="dock dock-sm"><a class="dock-active" href="/"><img width="16" height="16" src="home.svg"/><span class="dock-label">Home</span></a><a href="/settings"><img width="16" height="16" src="settings.svg"/><span class="dock-label">Settings</span></a></nav>"#;
+    let result = dioxus_ssr::render_element(Dock(props));
+    assert_eq!(result, expected);
+}
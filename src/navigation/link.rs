@@ -0,0 +1,129 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::classes;
+use crate::style::{Color, Modifier};
+
+fn is_external(href: &str) -> bool {
+    href.starts_with("http://") || href.starts_with("https://") || href.starts_with("//")
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct LinkProps {
+    href: String,
+    children: Element,
+    id: Option<String>,
+    class: Option<String>,
+    link_color: Option<Color>,
+    /// Only underline on hover
+    hover: Option<bool>,
+    /// Defaults to true for absolute URLs
+    external: Option<bool>,
+    target: Option<String>,
+    turbo_frame: Option<String>,
+    popover_target: Option<String>,
+    #[props(extends = GlobalAttributes, extends = a)]
+    attributes: Vec<Attribute>,
+}
+
+/// External links open in a new tab with `rel="noopener noreferrer"` and
+/// get an icon.
+#[component]
+pub fn Link(props: LinkProps) -> Element {
+    let link_color = props.link_color.unwrap_or_default().class("link");
+    let class = props.class.unwrap_or_default();
+    let hover = if props.hover.unwrap_or(false) {
+        "link-hover"
+    } else {
+        ""
+    };
+    let external = props.external.unwrap_or_else(|| is_external(&props.href));
+
+    if external {
+        rsx!(
+            a {
+                class: classes!("link", link_color, hover, class),
+                id: props.id,
+                href: "{props.href}",
+                target: props.target.unwrap_or("_blank".to_string()),
+                rel: "noopener noreferrer",
+                "data-turbo-frame": props.turbo_frame,
+                "data-target": props.popover_target,
+                ..props.attributes,
+                {props.children}
+                svg {
+                    xmlns: "http://www.w3.org/2000/svg",
+                    width: "12",
+                    height: "12",
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    class: "inline ml-1",
+                    "aria-hidden": "true",
+                    path { d: "M15 3h6v6" }
+                    path { d: "M10 14 21 3" }
+                    path { d: "M18 13v6a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2V8a2 2 0 0 1 2-2h6" }
+                }
+            }
+        )
+    } else {
+        rsx!(
+            a {
+                class: classes!("link", link_color, hover, class),
+                id: props.id,
+                href: "{props.href}",
+                target: props.target,
+                "data-turbo-frame": props.turbo_frame,
+                "data-target": props.popover_target,
+                ..props.attributes,
+                {props.children}
+            }
+        )
+    }
+}
+
+#[test]
+fn test_link() {
+    let props = LinkProps {
+        href: "/prompts".to_string(),
+        children: rsx!("Prompts"),
+        id: None,
+        class: None,
+        link_color: Some(Color::Primary),
+        hover: Some(true),
+        external: None,
+        target: None,
+        turbo_frame: Some("main-content".to_string()),
+        popover_target: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<a class="link link-primary link-hover" href="/prompts" data-turbo-frame="main-content">Prompts</a>"#;
+    let result = dioxus_ssr::render_element(Link(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_link_external() {
+    let props = LinkProps {
+        href: "https://daisyui.com".to_string(),
+        children: rsx!("Daisy"),
+        id: None,
+        class: None,
+        link_color: None,
+        hover: None,
+        external: None,
+        target: None,
+        turbo_frame: Some("main-content".to_string()),
+        popover_target: None,
+        attributes: vec![],
+    };
+
+    let result = dioxus_ssr::render_element(Link(props));
+    assert!(result.starts_with(
+        r#"<a class="link" href="https://daisyui.com" target="_blank" rel="noopener noreferrer" data-turbo-frame="main-content">Daisy<svg"#
+    ));
+}
@@ -0,0 +1,309 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::attributes::forward;
+use crate::classes;
+use crate::layout::divider::Divider;
+use crate::style::{Color, Modifier, Size};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MenuDirection {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+impl Display for MenuDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MenuDirection::Vertical => write!(f, "menu-vertical"),
+            MenuDirection::Horizontal => write!(f, "menu-horizontal"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MenuProps {
+    children: Element,
+    class: Option<String>,
+    direction: Option<MenuDirection>,
+    menu_size: Option<Size>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Menu(props: MenuProps) -> Element {
+    let direction = props.direction.unwrap_or_default();
+    let menu_size = props.menu_size.unwrap_or_default().class("menu");
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        ul { role: "list", class: classes!("menu", direction, menu_size, class), ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MenuTitleProps {
+    title: String,
+    class: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn MenuTitle(props: MenuTitleProps) -> Element {
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        li { class: classes!("menu-title", class), ..props.attributes, "{props.title}" }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MenuDividerProps {
+    text: Option<String>,
+    class: Option<String>,
+    divider_color: Option<Color>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A `Divider` wrapped in the `li` a menu list expects.
+#[component]
+pub fn MenuDivider(props: MenuDividerProps) -> Element {
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        li { class: "menu-divider", ..props.attributes,
+            Divider {
+                text: props.text,
+                class: classes!("my-1", class),
+                divider_color: props.divider_color,
+            }
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MenuItemProps {
+    children: Element,
+    href: Option<String>,
+    icon: Option<String>,
+    class: Option<String>,
+    active: Option<bool>,
+    disabled: Option<bool>,
+    #[props(extends = GlobalAttributes, extends = a)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn MenuItem(props: MenuItemProps) -> Element {
+    let active = if props.active.unwrap_or(false) {
+        "menu-active"
+    } else {
+        ""
+    };
+
+    if props.disabled.unwrap_or(false) {
+        rsx!(
+            li { class: "menu-disabled",
+                a { class: classes!(props.class.unwrap_or_default()), "aria-disabled": "true", ..props.attributes,
+                    if let Some(icon) = props.icon {
+                        img { width: "16", height: "16", src: "{icon}" }
+                    }
+                    {props.children}
+                }
+            }
+        )
+    } else {
+        rsx!(
+            li {
+                a {
+                    class: classes!(active, props.class.unwrap_or_default()),
+                    href: props.href,
+                    "aria-current": props.active.filter(|&a| a).map(|_| "page"),
+                    ..props.attributes,
+                    if let Some(icon) = props.icon {
+                        img { width: "16", height: "16", src: "{icon}" }
+                    }
+                    {props.children}
+                }
+            }
+        )
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct SubMenuProps {
+    title: String,
+    children: Element,
+    icon: Option<String>,
+    class: Option<String>,
+    /// Wrap in `details` so the submenu can be opened and closed
+    collapsible: Option<bool>,
+    open: Option<bool>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn SubMenu(props: SubMenuProps) -> Element {
+    if props.collapsible.unwrap_or(true) {
+        rsx!(
+            li { class: classes!(props.class.unwrap_or_default()), ..props.attributes,
+                details { open: props.open.filter(|&o| o),
+                    summary {
+                        if let Some(icon) = props.icon {
+                            img { width: "16", height: "16", src: "{icon}" }
+                        }
+                        "{props.title}"
+                    }
+                    ul { {props.children} }
+                }
+            }
+        )
+    } else {
+        rsx!(
+            li { class: classes!(props.class.unwrap_or_default()), ..props.attributes,
+                span {
+                    if let Some(icon) = props.icon {
+                        img { width: "16", height: "16", src: "{icon}" }
+                    }
+                    "{props.title}"
+                }
+                ul { {props.children} }
+            }
+        )
+    }
+}
+
+/// An entry for `MenuTree`. Items with `children` become submenus.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MenuNode {
+    pub id: String,
+    pub title: String,
+    pub href: Option<String>,
+    pub icon: Option<String>,
+    pub disabled: bool,
+    pub children: Vec<MenuNode>,
+}
+
+impl MenuNode {
+    /// True if this node or any of its descendants has the id.
+    pub fn contains(&self, id: &str) -> bool {
+        self.id == id || self.children.iter().any(|child| child.contains(id))
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct MenuTreeProps {
+    items: Vec<MenuNode>,
+    /// Id of the active item, its parent submenus are opened
+    selected: Option<String>,
+    class: Option<String>,
+    direction: Option<MenuDirection>,
+    menu_size: Option<Size>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn MenuTree(props: MenuTreeProps) -> Element {
+    let nodes = rsx! {
+        for node in props.items {
+            MenuNodeItem { node, selected: props.selected.clone() }
+        }
+    };
+
+    Menu(
+        forward(MenuProps::builder(), props.attributes)
+            .class(props.class)
+            .direction(props.direction)
+            .menu_size(props.menu_size)
+            .children(nodes)
+            .build(),
+    )
+}
+
+#[component]
+fn MenuNodeItem(node: MenuNode, selected: Option<String>) -> Element {
+    if node.children.is_empty() {
+        rsx!(
+            MenuItem {
+                href: node.href,
+                icon: node.icon,
+                disabled: node.disabled,
+                active: selected.as_deref() == Some(node.id.as_str()),
+                "{node.title}"
+            }
+        )
+    } else {
+        let open = selected.as_deref().is_some_and(|id| node.contains(id));
+        rsx!(
+            SubMenu { title: node.title, icon: node.icon, open,
+                for child in node.children {
+                    MenuNodeItem { node: child, selected: selected.clone() }
+                }
+            }
+        )
+    }
+}
+
+#[test]
+fn test_menu() {
+    let props = MenuProps {
+        children: rsx!(
+            MenuTitle { title: "Team" }
+            MenuItem { href: "/members", active: true, "Members" }
+            MenuDivider {}
+            MenuItem { disabled: true, "Billing" }
+        ),
+        class: None,
+        direction: Some(MenuDirection::Horizontal),
+        menu_size: Some(Size::Sm),
+        attributes: vec![],
+    };
+
+    let expected = r#"<ul role="list" class="menu menu-horizontal menu-sm"><li class="menu-title">Team</li><li><a class="menu-active" href="/members" aria-current="page">Members</a></li><li class="menu-divider"><div role="separator" aria-orientation="horizontal" class="divider divider-vertical my-1"></div></li><li class="menu-disabled"><a aria-disabled="true">Billing</a></li></ul>"#;
+    let result = dioxus_ssr::render_element(Menu(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_menu_tree_selected() {
+    let items = vec![
+        MenuNode {
+            id: "home".to_string(),
+            title: "Home".to_string(),
+            href: Some("/".to_string()),
+            ..Default::default()
+        },
+        MenuNode {
+            id: "settings".to_string(),
+            title: "Settings".to_string(),
+            children: vec![MenuNode {
+                id: "keys".to_string(),
+                title: "API Keys".to_string(),
+                href: Some("/keys".to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        },
+    ];
+
+    let props = MenuTreeProps {
+        items,
+        selected: Some("keys".to_string()),
+        class: None,
+        direction: None,
+        menu_size: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<ul role="list" class="menu menu-vertical menu-md"><li><a href="/">Home</a></li><li><details open=true><summary>Settings</summary><ul><li><a class="menu-active" href="/keys" aria-current="page">API Keys</a></li></ul></details></li></ul>"#;
+    let result = dioxus_ssr::render_element(MenuTree(props));
+    assert_eq!(result, expected);
+}
@@ -1,2 +1,9 @@
 pub mod breadcrumb;
+pub mod dock;
+pub mod link;
+pub mod menu;
+pub mod nav_tree;
+pub mod navbar;
 pub mod pagination;
+pub mod steps;
+pub mod tab;
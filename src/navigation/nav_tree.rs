@@ -0,0 +1,331 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::attributes::forward;
+use crate::navigation::breadcrumb::{Breadcrumb, BreadcrumbItem, BreadcrumbProps};
+use crate::navigation::dock::{Dock, DockItem, DockProps};
+use crate::navigation::menu::{Menu, MenuItem, MenuProps, MenuTitle, SubMenu};
+use crate::style::Size;
+
+/// One navigation model for the whole app. The sidebar, breadcrumb and dock
+/// are all rendered from it, with the active item found by matching the
+/// current path against each item's `href`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NavTree {
+    pub groups: Vec<NavTreeGroup>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NavTreeGroup {
+    pub heading: Option<String>,
+    pub items: Vec<NavTreeItem>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NavTreeItem {
+    pub id: String,
+    pub title: String,
+    pub href: String,
+    pub icon: Option<String>,
+    pub badge: Option<String>,
+    pub children: Vec<NavTreeItem>,
+}
+
+/// How closely `path` matches the route `href`, `None` if it doesn't. An exact
+/// match beats any prefix match, and longer prefixes beat shorter ones.
+pub(crate) fn route_match_len(href: &str, path: &str) -> Option<usize> {
+    let href = href.trim_end_matches('/');
+    let path = path.trim_end_matches('/');
+    if href == path {
+        Some(usize::MAX)
+    } else if !href.is_empty() && path.starts_with(href) && path[href.len()..].starts_with('/') {
+        Some(href.len())
+    } else {
+        None
+    }
+}
+
+fn best_trail<'a>(
+    items: &'a [NavTreeItem],
+    path: &str,
+    parents: &mut Vec<&'a NavTreeItem>,
+    best: &mut (usize, Vec<&'a NavTreeItem>),
+) {
+    for item in items {
+        parents.push(item);
+        if let Some(len) = route_match_len(&item.href, path) {
+            if best.1.is_empty() || len > best.0 {
+                *best = (len, parents.clone());
+            }
+        }
+        best_trail(&item.children, path, parents, best);
+        parents.pop();
+    }
+}
+
+impl NavTree {
+    /// The items from the top level down to the one that best matches `path`.
+    pub fn trail(&self, path: &str) -> Vec<&NavTreeItem> {
+        let mut best = (0, Vec::new());
+        for group in &self.groups {
+            best_trail(&group.items, path, &mut Vec::new(), &mut best);
+        }
+        best.1
+    }
+
+    pub fn active_id(&self, path: &str) -> Option<String> {
+        self.trail(path).last().map(|item| item.id.clone())
+    }
+
+    /// The trail as breadcrumbs, the last one (where we are) without a link.
+    pub fn breadcrumb_items(&self, path: &str) -> Vec<BreadcrumbItem> {
+        let trail = self.trail(path);
+        let last = trail.len().saturating_sub(1);
+        trail
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| BreadcrumbItem {
+                text: item.title.clone(),
+                href: (index != last).then(|| item.href.clone()),
+            })
+            .collect()
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavTreeSidebarProps {
+    tree: NavTree,
+    current_path: String,
+    class: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// A `Menu` per group, with the submenus leading to the active item opened.
+/// Extra attributes are set on every group's `Menu`.
+#[component]
+pub fn NavTreeSidebar(props: NavTreeSidebarProps) -> Element {
+    let trail: Vec<String> = props
+        .tree
+        .trail(&props.current_path)
+        .into_iter()
+        .map(|item| item.id.clone())
+        .collect();
+
+    let menus = props.tree.groups.into_iter().map(|group| {
+        let items = rsx!(
+            if let Some(heading) = group.heading {
+                MenuTitle { title: heading }
+            }
+            for item in group.items {
+                NavTreeNode { item, trail: trail.clone() }
+            }
+        );
+        Menu(
+            forward(MenuProps::builder(), props.attributes.clone())
+                .class(props.class.clone())
+                .children(items)
+                .build(),
+        )
+    });
+
+    rsx!({ menus })
+}
+
+/// Items with children become a `SubMenu`, with a link to the item's own
+/// page as its first entry.
+#[component]
+fn NavTreeNode(item: NavTreeItem, trail: Vec<String>) -> Element {
+    let active = trail.last() == Some(&item.id);
+    let has_children = !item.children.is_empty();
+
+    let link = rsx!(
+        MenuItem {
+            href: item.href,
+            icon: if has_children { None } else { item.icon.clone() },
+            active,
+            "{item.title}"
+            if let Some(badge) = item.badge {
+                span { class: "badge badge-sm", "{badge}" }
+            }
+        }
+    );
+
+    if !has_children {
+        link
+    } else {
+        rsx!(
+            SubMenu { title: item.title, icon: item.icon, open: trail.contains(&item.id),
+                {link}
+                for child in item.children {
+                    NavTreeNode { item: child, trail: trail.clone() }
+                }
+            }
+        )
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavTreeBreadcrumbProps {
+    tree: NavTree,
+    current_path: String,
+    class: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn NavTreeBreadcrumb(props: NavTreeBreadcrumbProps) -> Element {
+    Breadcrumb(
+        forward(BreadcrumbProps::builder(), props.attributes)
+            .items(props.tree.breadcrumb_items(&props.current_path))
+            .class(props.class)
+            .build(),
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavTreeDockProps {
+    tree: NavTree,
+    current_path: String,
+    class: Option<String>,
+    dock_size: Option<Size>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// The top level items that have an icon. The item is active when the
+/// current page is it or anything below it.
+#[component]
+pub fn NavTreeDock(props: NavTreeDockProps) -> Element {
+    let selected_item_id = props
+        .tree
+        .trail(&props.current_path)
+        .first()
+        .map(|item| item.id.clone());
+
+    let items = rsx!(
+        for item in props.tree.groups.into_iter().flat_map(|group| group.items) {
+            if let Some(icon) = item.icon {
+                DockItem {
+                    href: item.href,
+                    icon,
+                    title: item.title,
+                    id: item.id,
+                    selected_item_id: selected_item_id.clone(),
+                }
+            }
+        }
+    );
+
+    Dock(
+        forward(DockProps::builder(), props.attributes)
+            .class(props.class)
+            .dock_size(props.dock_size)
+            .children(items)
+            .build(),
+    )
+}
+
+#[cfg(test)]
+fn test_tree() -> NavTree {
+    NavTree {
+        groups: vec![NavTreeGroup {
+            heading: Some("Team".to_string()),
+            items: vec![
+                NavTreeItem {
+                    id: "home".to_string(),
+                    title: "Home".to_string(),
+                    href: "/".to_string(),
+                    icon: Some("home.svg".to_string()),
+                    ..Default::default()
+                },
+                NavTreeItem {
+                    id: "documents".to_string(),
+                    title: "Documents".to_string(),
+                    href: "/documents".to_string(),
+                    badge: Some("3".to_string()),
+                    children: vec![NavTreeItem {
+                        id: "upload".to_string(),
+                        title: "Upload".to_string(),
+                        href: "/documents/upload".to_string(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ],
+        }],
+    }
+}
+
+#[test]
+fn test_nav_tree_trail() {
+    let tree = test_tree();
+    assert_eq!(tree.active_id("/"), Some("home".to_string()));
+    assert_eq!(tree.active_id("/documents/"), Some("documents".to_string()));
+    assert_eq!(
+        tree.active_id("/documents/upload"),
+        Some("upload".to_string())
+    );
+    assert_eq!(
+        tree.active_id("/documents/42"),
+        Some("documents".to_string())
+    );
+    assert_eq!(tree.active_id("/documentsx"), None);
+    assert_eq!(
+        tree.breadcrumb_items("/documents/upload"),
+        vec![
+            BreadcrumbItem {
+                text: "Documents".to_string(),
+                href: Some("/documents".to_string()),
+            },
+            BreadcrumbItem {
+                text: "Upload".to_string(),
+                href: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_nav_tree_sidebar() {
+    let props = NavTreeSidebarProps {
+        tree: test_tree(),
+        current_path: "/documents/upload".to_string(),
+        class: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<ul role="list" class="menu menu-vertical menu-md"><li class="menu-title">Team</li><li><a href="/"><img width="16" height="16" src="home.svg"/>Home</a></li><li><details open=true><summary>Documents</summary><ul><li><a href="/documents">Documents<span class="badge badge-sm">3</span></a></li><li><a class="menu-active" href="/documents/upload" aria-current="page">Upload</a></li></ul></details></li></ul>"#;
+    let result = dioxus_ssr::render_element(NavTreeSidebar(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_nav_tree_sidebar_parent_page() {
+    let props = NavTreeSidebarProps {
+        tree: test_tree(),
+        current_path: "/documents".to_string(),
+        class: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<ul role="list" class="menu menu-vertical menu-md"><li class="menu-title">Team</li><li><a href="/"><img width="16" height="16" src="home.svg"/>Home</a></li><li><details open=true><summary>Documents</summary><ul><li><a class="menu-active" href="/documents" aria-current="page">Documents<span class="badge badge-sm">3</span></a></li><li><a href="/documents/upload">Upload</a></li></ul></details></li></ul>"#;
+    let result = dioxus_ssr::render_element(NavTreeSidebar(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_nav_tree_dock() {
+    let props = NavTreeDockProps {
+        tree: test_tree(),
+        current_path: "/".to_string(),
+        class: None,
+        dock_size: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<nav class="dock dock-md"><a class="dock-active" href="/"><img width="16" height="16" src="home.svg"/><span class="dock-label">Home</span></a></nav>"#;
+    let result = dioxus_ssr::render_element(NavTreeDock(props));
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,126 @@
+#![allow(non_snake_case)]
+use dioxus::prelude::*;
+
+use crate::classes;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavbarProps {
+    class: Option<String>,
+    /// Logo or site name, first thing in `navbar-start`
+    brand: Option<Element>,
+    start: Option<Element>,
+    /// `li` items shown as a horizontal menu, collapsed into a
+    /// dropdown on small screens
+    links: Option<Element>,
+    center: Option<Element>,
+    end: Option<Element>,
+    sticky: Option<bool>,
+    glass: Option<bool>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Navbar(props: NavbarProps) -> Element {
+    let class = props.class.unwrap_or_default();
+    let sticky = if props.sticky.unwrap_or(false) {
+        "sticky top-0 z-30"
+    } else {
+        ""
+    };
+    let background = if props.glass.unwrap_or(false) {
+        "glass"
+    } else {
+        "bg-base-100"
+    };
+
+    rsx!(
+        div { class: classes!("navbar", background, sticky, class), ..props.attributes,
+            div { class: "navbar-start",
+                if let Some(links) = props.links.clone() {
+                    div { class: "dropdown",
+                        div {
+                            tabindex: "0",
+                            role: "button",
+                            class: "btn btn-ghost lg:hidden",
+                            "aria-label": "Open menu",
+                            svg {
+                                xmlns: "http://www.w3.org/2000/svg",
+                                width: "20",
+                                height: "20",
+                                view_box: "0 0 24 24",
+                                fill: "none",
+                                stroke: "currentColor",
+                                stroke_width: "2",
+                                stroke_linecap: "round",
+                                stroke_linejoin: "round",
+                                path { d: "M4 6h16M4 12h16M4 18h16" }
+                            }
+                        }
+                        ul {
+                            tabindex: "0",
+                            class: "menu menu-sm dropdown-content bg-base-100 rounded-box z-1 mt-3 w-52 p-2 shadow",
+                            {links}
+                        }
+                    }
+                }
+                {props.brand}
+                {props.start}
+            }
+            div { class: "navbar-center",
+                if let Some(links) = props.links {
+                    ul { class: "menu menu-horizontal px-1 hidden lg:flex", {links} }
+                }
+                {props.center}
+            }
+            div { class: "navbar-end", {props.end} }
+        }
+    )
+}
+
+#[test]
+fn test_navbar() {
+    let props = NavbarProps {
+        class: None,
+        brand: Some(rsx!(
+            a { class: "btn btn-ghost text-xl", href: "/", "Bionic" }
+        )),
+        start: None,
+        links: None,
+        center: None,
+        end: Some(rsx!(
+            a { class: "btn", href: "/login", "Login" }
+        )),
+        sticky: Some(true),
+        glass: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<div class="navbar bg-base-100 sticky top-0 z-30"><div class="navbar-start"><a class="btn btn-ghost text-xl" href="/">Bionic</a></div><div class="navbar-center"></div><div class="navbar-end"><a class="btn" href="/login">Login</a></div></div>"#;
+    let result = dioxus_ssr::render_element(Navbar(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_navbar_links_collapse() {
+    let props = NavbarProps {
+        class: None,
+        brand: None,
+        start: None,
+        links: Some(rsx!(
+            li {
+                a { href: "/pricing", "Pricing" }
+            }
+        )),
+        center: None,
+        end: None,
+        sticky: None,
+        glass: Some(true),
+        attributes: vec![],
+    };
+
+    let result = dioxus_ssr::render_element(Navbar(props));
+    assert!(result.starts_with(r#"<div class="navbar glass">"#));
+    assert!(result.contains(r#"<ul tabindex="0" class="menu menu-sm dropdown-content bg-base-100 rounded-box z-1 mt-3 w-52 p-2 shadow"><li><a href="/pricing">Pricing</a></li></ul>"#));
+    assert!(result.contains(r#"<div class="navbar-center"><ul class="menu menu-horizontal px-1 hidden lg:flex"><li><a href="/pricing">Pricing</a></li></ul></div>"#));
+}
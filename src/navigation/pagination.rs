@@ -5,6 +5,8 @@ use dioxus::prelude::*;
 pub struct PaginationProps {
     next_page_url: Option<String>,
     prev_page_url: Option<String>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
 }
 
 #[component]
@@ -13,6 +15,7 @@ pub fn Pagination(props: PaginationProps) -> Element {
         nav {
             class: "paginate-container",
             "aria-label": "Pagination",
+            ..props.attributes,
             div {
                 class: "pagination",
                 if let Some(url) = props.prev_page_url {
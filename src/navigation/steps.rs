@@ -0,0 +1,168 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::attributes::forward;
+use crate::classes;
+use crate::style::{Color, Modifier};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StepsDirection {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+impl Display for StepsDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepsDirection::Horizontal => write!(f, "steps-horizontal"),
+            StepsDirection::Vertical => write!(f, "steps-vertical"),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StepState {
+    Completed,
+    Current,
+    Upcoming,
+}
+
+impl StepState {
+    pub fn new(index: usize, current: usize) -> Self {
+        match index.cmp(&current) {
+            std::cmp::Ordering::Less => StepState::Completed,
+            std::cmp::Ordering::Equal => StepState::Current,
+            std::cmp::Ordering::Greater => StepState::Upcoming,
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StepsProps {
+    children: Element,
+    class: Option<String>,
+    direction: Option<StepsDirection>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Steps(props: StepsProps) -> Element {
+    let direction = props.direction.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        ul { class: classes!("steps", direction, class), ..props.attributes, {props.children} }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct StepProps {
+    children: Element,
+    class: Option<String>,
+    step_color: Option<Color>,
+    /// Shown in the circle instead of the step number
+    content: Option<String>,
+    current: Option<bool>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn Step(props: StepProps) -> Element {
+    let step_color = props.step_color.unwrap_or_default().class("step");
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        li {
+            class: classes!("step", step_color, class),
+            "data-content": props.content,
+            "aria-current": props.current.filter(|&c| c).map(|_| "step"),
+            ..props.attributes,
+            {props.children}
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct WizardStepsProps {
+    names: Vec<String>,
+    /// Index into `names` of the step we are on
+    current: usize,
+    class: Option<String>,
+    direction: Option<StepsDirection>,
+    step_color: Option<Color>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// Steps before `current` are completed and get a tick, the current step is
+/// coloured too and the rest are left plain.
+#[component]
+pub fn WizardSteps(props: WizardStepsProps) -> Element {
+    let step_color = props.step_color.unwrap_or(Color::Primary);
+
+    let steps = rsx! {
+        for (index, name) in props.names.into_iter().enumerate() {
+            match StepState::new(index, props.current) {
+                StepState::Completed => rsx!(
+                    Step { step_color, content: "✓", "{name}" }
+                ),
+                StepState::Current => rsx!(
+                    Step { step_color, current: true, "{name}" }
+                ),
+                StepState::Upcoming => rsx!(
+                    Step { "{name}" }
+                ),
+            }
+        }
+    };
+
+    Steps(
+        forward(StepsProps::builder(), props.attributes)
+            .class(props.class)
+            .direction(props.direction)
+            .children(steps)
+            .build(),
+    )
+}
+
+#[test]
+fn test_steps() {
+    let props = StepsProps {
+        children: rsx!(
+            Step { step_color: Color::Info, content: "?", "Register" }
+            Step { "Purchase" }
+        ),
+        class: None,
+        direction: Some(StepsDirection::Vertical),
+        attributes: vec![],
+    };
+
+    let expected = r#"<ul class="steps steps-vertical"><li class="step step-info" data-content="?">Register</li><li class="step">Purchase</li></ul>"#;
+    let result = dioxus_ssr::render_element(Steps(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_wizard_steps() {
+    let props = WizardStepsProps {
+        names: vec![
+            "Account".to_string(),
+            "Model".to_string(),
+            "Finish".to_string(),
+        ],
+        current: 1,
+        class: None,
+        direction: None,
+        step_color: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<ul class="steps steps-horizontal"><li class="step step-primary" data-content="✓">Account</li><li class="step step-primary" aria-current="step">Model</li><li class="step">Finish</li></ul>"#;
+    let result = dioxus_ssr::render_element(WizardSteps(props));
+    assert_eq!(result, expected);
+}
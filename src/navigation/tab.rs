@@ -0,0 +1,193 @@
+#![allow(non_snake_case)]
+use std::fmt::Display;
+
+use dioxus::prelude::*;
+
+use crate::attributes::forward;
+use crate::classes;
+use crate::navigation::nav_tree::route_match_len;
+use crate::style::{Modifier, Size};
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TabsStyle {
+    None,
+    #[default]
+    Border,
+    Lift,
+    Box,
+}
+
+impl Display for TabsStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TabsStyle::None => write!(f, ""),
+            TabsStyle::Border => write!(f, "tabs-border"),
+            TabsStyle::Lift => write!(f, "tabs-lift"),
+            TabsStyle::Box => write!(f, "tabs-box"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TabsPlacement {
+    #[default]
+    Top,
+    Bottom,
+}
+
+impl Display for TabsPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TabsPlacement::Top => write!(f, "tabs-top"),
+            TabsPlacement::Bottom => write!(f, "tabs-bottom"),
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct TabsProps {
+    children: Element,
+    class: Option<String>,
+    tabs_style: Option<TabsStyle>,
+    tabs_size: Option<Size>,
+    placement: Option<TabsPlacement>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+/// The tab list. Holds `TabPanel`s for radio driven tabs, which is a drop in
+/// replacement for `TabContainer`, or `LinkTab`s for server routed tabs.
+#[component]
+pub fn Tabs(props: TabsProps) -> Element {
+    let tabs_style = props.tabs_style.unwrap_or_default();
+    let tabs_size = props.tabs_size.unwrap_or_default().class("tabs");
+    let placement = props.placement.unwrap_or_default();
+    let class = props.class.unwrap_or_default();
+
+    rsx!(
+        div { role: "tablist", class: classes!("tabs", tabs_style, tabs_size, placement, class), ..props.attributes,
+            {props.children}
+        }
+    )
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct LinkTabProps {
+    href: String,
+    children: Element,
+    class: Option<String>,
+    active: Option<bool>,
+    disabled: Option<bool>,
+    #[props(extends = GlobalAttributes, extends = a)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn LinkTab(props: LinkTabProps) -> Element {
+    let active = if props.active.unwrap_or(false) {
+        "tab-active"
+    } else {
+        ""
+    };
+    let class = props.class.unwrap_or_default();
+
+    if props.disabled.unwrap_or(false) {
+        rsx!(
+            a { role: "tab", class: classes!("tab tab-disabled", class), "aria-disabled": "true", ..props.attributes, {props.children} }
+        )
+    } else {
+        rsx!(
+            a {
+                role: "tab",
+                class: classes!("tab", active, class),
+                href: "{props.href}",
+                "aria-selected": props.active.filter(|&a| a).map(|_| "true"),
+                ..props.attributes,
+                {props.children}
+            }
+        )
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct LinkTabsProps {
+    /// `(href, label)` for each tab
+    tabs: Vec<(String, String)>,
+    /// The tab whose `href` best matches this is active
+    current_path: String,
+    class: Option<String>,
+    tabs_style: Option<TabsStyle>,
+    tabs_size: Option<Size>,
+    placement: Option<TabsPlacement>,
+    #[props(extends = GlobalAttributes)]
+    attributes: Vec<Attribute>,
+}
+
+#[component]
+pub fn LinkTabs(props: LinkTabsProps) -> Element {
+    let active_index = props
+        .tabs
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (href, _))| {
+            route_match_len(href, &props.current_path).map(|len| (len, index))
+        })
+        .max()
+        .map(|(_, index)| index);
+
+    let tabs = rsx!(
+        for (index , (href , label)) in props.tabs.into_iter().enumerate() {
+            LinkTab { href, active: Some(index) == active_index, "{label}" }
+        }
+    );
+
+    Tabs(
+        forward(TabsProps::builder(), props.attributes)
+            .class(props.class)
+            .tabs_style(props.tabs_style)
+            .tabs_size(props.tabs_size)
+            .placement(props.placement)
+            .children(tabs)
+            .build(),
+    )
+}
+
+#[test]
+fn test_tabs_with_panels() {
+    use crate::tab_container::TabPanel;
+
+    let props = TabsProps {
+        children: rsx!(
+            TabPanel { name: "tabs", tab_name: "One", checked: true, "First" }
+        ),
+        class: None,
+        tabs_style: Some(TabsStyle::Lift),
+        tabs_size: Some(Size::Sm),
+        placement: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<div role="tablist" class="tabs tabs-lift tabs-sm tabs-top"><input checked=true type="radio" class="tab" aria-label="One" name="tabs"/><div role="tabpanel" class="tab-content">First</div></div>"#;
+    let result = dioxus_ssr::render_element(Tabs(props));
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn test_link_tabs() {
+    let props = LinkTabsProps {
+        tabs: vec![
+            ("/settings".to_string(), "General".to_string()),
+            ("/settings/keys".to_string(), "Keys".to_string()),
+        ],
+        current_path: "/settings/keys/new".to_string(),
+        class: None,
+        tabs_style: Some(TabsStyle::Box),
+        tabs_size: None,
+        placement: None,
+        attributes: vec![],
+    };
+
+    let expected = r#"<div role="tablist" class="tabs tabs-box tabs-md tabs-top"><a role="tab" class="tab" href="/settings">General</a><a role="tab" class="tab tab-active" href="/settings/keys" aria-selected="true">Keys</a></div>"#;
+    let result = dioxus_ssr::render_element(LinkTabs(props));
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,144 @@
+//! The DaisyUI palette, sizes and style variants shared by every component.
+//!
+//! Each component prefixes the modifier with its own name, so
+//! `Color::Primary.class("btn")` is `btn-primary` and
+//! `Size::Lg.class("badge")` is `badge-lg`.
+
+/// Maps a value onto the suffix of a DaisyUI modifier class.
+pub trait Modifier {
+    /// The class suffix, `None` when the value adds no class.
+    fn modifier(&self) -> Option<&'static str>;
+
+    /// The component prefixed class, or an empty string.
+    fn class(&self, prefix: &str) -> String {
+        self.modifier()
+            .map(|modifier| format!("{prefix}-{modifier}"))
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Color {
+    /// The component's own colour, no modifier class.
+    #[default]
+    Default,
+    Neutral,
+    Primary,
+    Secondary,
+    Accent,
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl Modifier for Color {
+    fn modifier(&self) -> Option<&'static str> {
+        match self {
+            Color::Default => None,
+            Color::Neutral => Some("neutral"),
+            Color::Primary => Some("primary"),
+            Color::Secondary => Some("secondary"),
+            Color::Accent => Some("accent"),
+            Color::Info => Some("info"),
+            Color::Success => Some("success"),
+            Color::Warning => Some("warning"),
+            Color::Error => Some("error"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Size {
+    Xs,
+    Sm,
+    #[default]
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Modifier for Size {
+    fn modifier(&self) -> Option<&'static str> {
+        match self {
+            Size::Xs => Some("xs"),
+            Size::Sm => Some("sm"),
+            Size::Md => Some("md"),
+            Size::Lg => Some("lg"),
+            Size::Xl => Some("xl"),
+        }
+    }
+}
+
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Variant {
+    /// Solid, no modifier class.
+    #[default]
+    Default,
+    Outline,
+    Dash,
+    Soft,
+    Ghost,
+    Link,
+}
+
+impl Modifier for Variant {
+    fn modifier(&self) -> Option<&'static str> {
+        match self {
+            Variant::Default => None,
+            Variant::Outline => Some("outline"),
+            Variant::Dash => Some("dash"),
+            Variant::Soft => Some("soft"),
+            Variant::Ghost => Some("ghost"),
+            Variant::Link => Some("link"),
+        }
+    }
+}
+
+/// Bridges one of the older per component enums onto `Color`, `Size` or
+/// `Variant`, so props typed with the shared enum still accept it. With a
+/// prefix it also keeps the old `Display`, rendered through `Modifier`.
+macro_rules! legacy_enum {
+    ($legacy:ident => $shared:ident { $($from:ident => $to:ident),* $(,)? }) => {
+        impl From<$legacy> for $crate::style::$shared {
+            fn from(value: $legacy) -> Self {
+                match value {
+                    $($legacy::$from => $crate::style::$shared::$to,)*
+                }
+            }
+        }
+
+        impl From<$legacy> for Option<$crate::style::$shared> {
+            fn from(value: $legacy) -> Self {
+                Some(value.into())
+            }
+        }
+    };
+    ($legacy:ident => $shared:ident, $prefix:literal { $($from:ident => $to:ident),* $(,)? }) => {
+        $crate::style::legacy_enum!($legacy => $shared { $($from => $to),* });
+
+        impl std::fmt::Display for $legacy {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let shared = $crate::style::$shared::from(*self);
+                write!(f, "{}", $crate::style::Modifier::class(&shared, $prefix))
+            }
+        }
+    };
+}
+
+pub(crate) use legacy_enum;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefixed_classes() {
+        assert_eq!(Color::Primary.class("btn"), "btn-primary");
+        assert_eq!(Color::Warning.class("text"), "text-warning");
+        assert_eq!(Color::Default.class("badge"), "");
+        assert_eq!(Size::Sm.class("loading"), "loading-sm");
+        assert_eq!(Variant::Soft.class("badge"), "badge-soft");
+        assert_eq!(Variant::Default.class("btn"), "");
+    }
+}
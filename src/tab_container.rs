@@ -3,6 +3,8 @@
 
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct TabContainerProps {
     class: Option<String>,
@@ -14,7 +16,7 @@ pub fn TabContainer(props: TabContainerProps) -> Element {
     rsx!(
         div {
             role: "tablist",
-            class: "tabs tabs-border {props.class.clone().unwrap_or_default()}",
+            class: classes!("tabs tabs-border", props.class.clone().unwrap_or_default()),
             {props.children}
         }
     )
@@ -24,17 +26,26 @@ pub fn TabContainer(props: TabContainerProps) -> Element {
 pub struct TabPanelProps {
     name: String,
     checked: Option<bool>,
+    disabled: Option<bool>,
     tab_name: String,
     children: Element,
 }
 
 #[component]
 pub fn TabPanel(props: TabPanelProps) -> Element {
+    let disabled = props.disabled.filter(|&d| d);
+    let class = if disabled.is_some() {
+        "tab tab-disabled"
+    } else {
+        "tab"
+    };
+
     rsx!(
         input {
             checked: props.checked,
             "type": "radio",
-            class: "tab",
+            class,
+            disabled,
             "aria-label": props.tab_name,
             name: props.name,
         }
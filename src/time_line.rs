@@ -3,6 +3,8 @@
 
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Props, Clone, PartialEq)]
 pub struct TimeLineProps {
     condensed: Option<bool>,
@@ -18,7 +20,7 @@ pub fn TimeLine(props: TimeLineProps) -> Element {
     };
 
     rsx!(
-        div { class: "{condensed} timeline-item {props.class.clone().unwrap_or_default()}",
+        div { class: classes!(condensed, "timeline-item", props.class.clone().unwrap_or_default()),
             {props.children}
         }
     )
@@ -33,7 +35,7 @@ pub struct TimeLineBadgeProps {
 #[component]
 pub fn TimeLineBadge(props: TimeLineBadgeProps) -> Element {
     rsx!(
-        div { class: "timeline-badge {props.class.clone().unwrap_or_default()}",
+        div { class: classes!("timeline-badge", props.class.clone().unwrap_or_default()),
             img { src: "{props.image_src}", width: "16" }
         }
     )
@@ -48,6 +50,6 @@ pub struct TimeLineBodyProps {
 #[component]
 pub fn TimeLineBody(props: TimeLineBodyProps) -> Element {
     rsx!(
-        div { class: "timeline-body {props.class.clone().unwrap_or_default()}", {props.children} }
+        div { class: classes!("timeline-body", props.class.clone().unwrap_or_default()), {props.children} }
     )
 }
@@ -4,6 +4,8 @@
 use std::fmt::Display;
 use dioxus::prelude::*;
 
+use crate::classes;
+
 #[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
 pub enum TimelineDirection {
     #[default]
@@ -37,7 +39,7 @@ pub fn Timeline(props: TimelineProps) -> Element {
     let class = props.class.unwrap_or_default();
 
     rsx!(
-        ul { class: "timeline {direction} {compact} {snap_icon} {class}",
+        ul { class: classes!("timeline", direction, compact, snap_icon, class),
             {props.children}
         }
     )
@@ -52,7 +54,7 @@ pub struct TimelineItemProps {
 #[component]
 pub fn TimelineItem(props: TimelineItemProps) -> Element {
     rsx!(
-        li { class: "{props.class.clone().unwrap_or_default()}", {props.children} }
+        li { class: classes!(props.class.clone().unwrap_or_default()), {props.children} }
     )
 }
 
@@ -67,14 +69,14 @@ pub struct TimelinePartProps {
 pub fn TimelineStart(props: TimelinePartProps) -> Element {
     let boxed = if props.boxed.unwrap_or(false) { "timeline-box" } else { "" };
     rsx!(
-        div { class: "timeline-start {boxed} {props.class.clone().unwrap_or_default()}", {props.children} }
+        div { class: classes!("timeline-start", boxed, props.class.clone().unwrap_or_default()), {props.children} }
     )
 }
 
 #[component]
 pub fn TimelineMiddle(props: TimelinePartProps) -> Element {
     rsx!(
-        div { class: "timeline-middle {props.class.clone().unwrap_or_default()}", {props.children} }
+        div { class: classes!("timeline-middle", props.class.clone().unwrap_or_default()), {props.children} }
     )
 }
 
@@ -82,6 +84,6 @@ pub fn TimelineMiddle(props: TimelinePartProps) -> Element {
 pub fn TimelineEnd(props: TimelinePartProps) -> Element {
     let boxed = if props.boxed.unwrap_or(false) { "timeline-box" } else { "" };
     rsx!(
-        div { class: "timeline-end {boxed} {props.class.clone().unwrap_or_default()}", {props.children} }
+        div { class: classes!("timeline-end", boxed, props.class.clone().unwrap_or_default()), {props.children} }
     )
 }